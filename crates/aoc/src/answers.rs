@@ -0,0 +1,99 @@
+use anyhow::{anyhow, Error};
+use common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+// Written at the top of the file, since the entries are rewritten in order on every save
+const HEADER: &str = "\
+# Known-correct answers for each day, part and bundled input, checked by `aoc verify` and `cargo test -p aoc`.
+# Record new ones with `aoc verify --record` once they've been checked by hand.
+#
+# Parts that aren't implemented yet (day 17 part 2) or don't exist (day 25 part 2) have no entry.
+# Day 15 recognizes its example and solves it with the example's own row and search area, rather than the real input's.
+";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Entry {
+    pub day: u32,
+    pub part: u8,
+    pub input: String, // file name within the day's crate, like input02.txt
+    pub answer: Answer,
+}
+
+impl Entry {
+    pub fn part(&self) -> Result<Part, Error> {
+        match self.part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(anyhow!("Day {} has invalid part {part}", self.day)),
+        }
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+// The answers store, answers.toml in the workspace
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    entries: Vec<Entry>,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = read_to_string(path).map_err(|err| anyhow!("{}: {err}", path.display()))?;
+        toml::from_str(&text).map_err(|err| anyhow!("{}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = format!("{HEADER}\n{}", toml::to_string(self)?);
+        write(path, text).map_err(|err| anyhow!("{}: {err}", path.display()))
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, day: u32, part: Part, input: &str) -> Option<&Answer> {
+        let part = part_number(part);
+        self.entries
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part && entry.input == input)
+            .map(|entry| &entry.answer)
+    }
+
+    // Adds an answer, or replaces the one already known
+    pub fn insert(&mut self, day: u32, part: Part, input: &str, answer: Answer) {
+        let part = part_number(part);
+        let entry = Entry {
+            day,
+            part,
+            input: input.to_owned(),
+            answer,
+        };
+
+        let existing = self
+            .entries
+            .iter_mut()
+            .find(|e| e.day == day && e.part == part && e.input == input);
+
+        match existing {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+
+        // both parts of an input stay together, as they're usually checked together
+        self.entries
+            .sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+    }
+}
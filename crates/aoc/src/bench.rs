@@ -0,0 +1,189 @@
+use anyhow::Error;
+use aoc::Day;
+use common::{phase, Part};
+use std::collections::HashMap;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::{ErrorKind, Write as _};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Timings from different builds aren't comparable, so each record notes which one it came from
+const PROFILE: &str = if cfg!(debug_assertions) {
+    "debug"
+} else {
+    "release"
+};
+
+const HISTORY_HEADER: &str = "run,profile,day,part,phase,iterations,median_ns";
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Key {
+    day: u32,
+    part: String,
+    phase: String,
+}
+
+pub struct Record {
+    day: u32,
+    part: Part,
+    phase: &'static str,
+    median: Duration,
+}
+
+// Runs one part repeatedly, returning the median time of each phase
+//
+// As with phase::profile, time not covered by a phase the solver reports is counted as "solve", and "total" covers
+// the whole call.
+fn bench_part(
+    day: &Day,
+    part: Part,
+    input: &str,
+    iterations: u32,
+) -> Result<Vec<(&'static str, Duration)>, Error> {
+    // warm up, and make sure the part works before spending time on it
+    day.solve(part, input)?;
+
+    let mut samples: Vec<(&'static str, Vec<Duration>)> = Vec::new();
+
+    for _ in 0..iterations {
+        let (answer, phases) = phase::profile(|| day.solve(part, input));
+        answer?;
+
+        for phase in phases {
+            match samples.iter_mut().find(|(name, _)| *name == phase.name) {
+                Some((_, times)) => times.push(phase.elapsed),
+                None => samples.push((phase.name, vec![phase.elapsed])),
+            }
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(name, mut times)| {
+            times.sort_unstable();
+            (name, times[times.len() / 2])
+        })
+        .collect())
+}
+
+pub fn bench_day(day: &Day, parts: &[Part], iterations: u32, records: &mut Vec<Record>) {
+    let input_path = day.input_path("input02.txt");
+
+    let input = match read_to_string(&input_path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}: skipped: {}: {err}", day.name(), input_path.display());
+            return;
+        }
+    };
+
+    for part in parts {
+        match bench_part(day, *part, &input, iterations) {
+            Ok(medians) => {
+                for (phase, median) in medians {
+                    records.push(Record {
+                        day: day.number,
+                        part: *part,
+                        phase,
+                        median,
+                    });
+                }
+            }
+            Err(err) => eprintln!("{} part {part}: skipped: {err:#}", day.name()),
+        }
+    }
+}
+
+// Finds the most recent median for each day, part and phase recorded by this profile
+fn load_previous(history: &Path) -> Result<HashMap<Key, Duration>, Error> {
+    let contents = match read_to_string(history) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut previous = HashMap::new();
+
+    for line in contents.lines().filter(|line| *line != HISTORY_HEADER) {
+        let fields = line.split(',').collect::<Vec<_>>();
+        let [_run, profile, day, part, phase, _iterations, median_ns] = fields[..] else {
+            continue;
+        };
+
+        if profile != PROFILE {
+            continue;
+        }
+
+        let key = Key {
+            day: day.parse()?,
+            part: part.to_owned(),
+            phase: phase.to_owned(),
+        };
+
+        previous.insert(key, Duration::from_nanos(median_ns.parse()?));
+    }
+
+    Ok(previous)
+}
+
+fn append_history(history: &Path, iterations: u32, records: &[Record]) -> Result<(), Error> {
+    let run = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+    let mut file = OpenOptions::new().create(true).append(true).open(history)?;
+
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{HISTORY_HEADER}")?;
+    }
+
+    for record in records {
+        writeln!(
+            file,
+            "{run},{PROFILE},{},{},{},{iterations},{}",
+            record.day,
+            record.part,
+            record.phase,
+            record.median.as_nanos()
+        )?;
+    }
+
+    Ok(())
+}
+
+fn print_table(records: &[Record], previous: &HashMap<Key, Duration>) {
+    println!(
+        "Day  Part  {:10}  {:>10}  {:>10}  Change",
+        "Phase", "Median", "Previous"
+    );
+    println!("---  ----  ----------  {:->10}  {:->10}  ------", "", "");
+
+    for record in records {
+        let key = Key {
+            day: record.day,
+            part: record.part.to_string(),
+            phase: record.phase.to_owned(),
+        };
+
+        let median = format!("{:.2?}", record.median);
+
+        let (prev, change) = match previous.get(&key) {
+            Some(prev) if !prev.is_zero() => {
+                let change = (record.median.as_secs_f64() / prev.as_secs_f64() - 1.0) * 100.0;
+                (format!("{prev:.2?}"), format!("{change:+.1}%"))
+            }
+            Some(prev) => (format!("{prev:.2?}"), String::new()),
+            None => (String::new(), String::new()),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:10}  {median:>10}  {prev:>10}  {change}",
+            record.day, record.part, record.phase
+        );
+    }
+}
+
+// Prints the new timings next to the previous run's, then adds them to the history
+pub fn report(history: &Path, iterations: u32, records: &[Record]) -> Result<(), Error> {
+    let previous = load_previous(history)?;
+    print_table(records, &previous);
+    append_history(history, iterations, records)
+}
@@ -0,0 +1,83 @@
+use anyhow::{anyhow, Error};
+use common::{Answer, Part};
+use std::path::PathBuf;
+
+pub mod answers;
+
+type SolveFn = fn(&str) -> Result<Answer, Error>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    part1: SolveFn,
+    part2: SolveFn,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+
+    // the puzzle input bundled with the day's crate (input01.txt is the example, input02.txt the real input)
+    pub fn input_path(&self, file_name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.name())
+            .join(file_name)
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer, Error> {
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+}
+
+macro_rules! days {
+    ($($number:literal => $krate:ident),* $(,)?) => {
+        pub const DAYS: &[Day] = &[
+            $(
+                Day {
+                    number: $number,
+                    part1: $krate::part1,
+                    part2: $krate::part2,
+                },
+            )*
+        ];
+    };
+}
+
+days! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20,
+    21 => day21,
+    22 => day22,
+    23 => day23,
+    24 => day24,
+    25 => day25,
+}
+
+pub fn find_day(number: u32) -> Result<&'static Day, Error> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| anyhow!("Day {number} is not implemented"))
+}
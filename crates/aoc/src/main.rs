@@ -0,0 +1,464 @@
+use anyhow::{anyhow, bail, Error};
+use aoc::answers::Answers;
+use aoc::{Day, DAYS};
+use clap::{Parser, Subcommand};
+use common::alloc::CountingAlloc;
+use common::{
+    detail, phase, Answer, Details, Format, ImageArgs, LogArgs, Part, PartSelection, Phase, Report,
+};
+use std::fs::{read_to_string, write};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod bench;
+mod step;
+mod verify;
+
+// counted for run --profile
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    log: LogArgs,
+
+    #[command(flatten)]
+    images: ImageArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or all of them, and print a table of answers
+    Run {
+        /// Day to solve
+        #[arg(required_unless_present = "all")]
+        day: Option<u32>,
+
+        /// Solve every day
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+
+        /// Which part of the puzzle to solve
+        #[arg(long, value_enum, default_value_t)]
+        part: PartSelection,
+
+        /// Input file (defaults to the day's bundled input02.txt; days without one have no input to solve)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Also report the time and allocations of each phase (parse, preprocess, solve) of each part
+        #[arg(long)]
+        profile: bool,
+    },
+
+    /// Time the parse and solve phases of each day and record them in a history file
+    Bench {
+        /// Day to benchmark (defaults to every day)
+        day: Option<u32>,
+
+        /// Which part of the puzzle to benchmark
+        #[arg(long, value_enum, default_value_t)]
+        part: PartSelection,
+
+        /// Number of timed runs of each part
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// History file to compare against and append to (defaults to bench-history.csv in the workspace)
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+
+    /// Step through a day's simulation in the terminal (days 5, 9, 14, 17, 22, 23 and 24 have steps to show)
+    Step {
+        /// Day to step through
+        day: u32,
+
+        /// Which part of the puzzle to solve
+        #[arg(long, value_enum, default_value = "1")]
+        part: PartSelection,
+
+        /// Input file (defaults to the day's bundled example, input01.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Milliseconds between steps while playing
+        #[arg(long, default_value_t = 200)]
+        delay: u64,
+
+        /// Number of past steps to keep for seeking back
+        #[arg(long, default_value_t = 10_000)]
+        history: usize,
+    },
+
+    /// Check each day's answers against the answers store, reporting each as pass, fail or unknown
+    Verify {
+        /// Day to verify (defaults to every day)
+        day: Option<u32>,
+
+        /// Which part of the puzzle to verify
+        #[arg(long, value_enum, default_value_t)]
+        part: PartSelection,
+
+        /// Input file name within each day's crate, like input02.txt (defaults to all of them)
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Save answers that aren't in the store yet, once they've been checked by hand
+        #[arg(long)]
+        record: bool,
+
+        /// With --record, also replace stored answers that differ
+        #[arg(long, requires = "record")]
+        update: bool,
+
+        /// Answers store (defaults to answers.toml in the workspace)
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+
+    /// Write a random puzzle input for a day, for stress testing
+    Gen {
+        /// Day to write an input for
+        day: u32,
+
+        /// Seed for the random number generator; the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many elements (lines, monkeys, cubes...) the input has
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// File to write the input to (defaults to stdout)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+struct Row {
+    day: u32,
+    part: Part,
+    answer: Option<Result<Answer, Error>>, // None for a day with no bundled input to solve
+    elapsed: Duration,
+    details: Details,
+    phases: Vec<Phase>,
+}
+
+fn solve_day(day: &Day, parts: &[Part], input_path: Option<PathBuf>, rows: &mut Vec<Row>) {
+    let bundled = input_path.is_none();
+    let input_path = input_path.unwrap_or_else(|| day.input_path("input02.txt"));
+
+    let input = match read_to_string(&input_path) {
+        Ok(input) => input,
+        Err(err) => {
+            // not every day has its real input bundled, which is no failure of the day's
+            let missing = bundled && err.kind() == ErrorKind::NotFound;
+
+            for part in parts {
+                rows.push(Row {
+                    day: day.number,
+                    part: *part,
+                    answer: (!missing).then(|| Err(anyhow!("{}: {err}", input_path.display()))),
+                    elapsed: Duration::ZERO,
+                    details: Details::new(),
+                    phases: Vec::new(),
+                });
+            }
+
+            return;
+        }
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let ((answer, details), phases) =
+            phase::profile(|| detail::record(|| day.solve(*part, &input)));
+        let elapsed = start.elapsed();
+
+        rows.push(Row {
+            day: day.number,
+            part: *part,
+            answer: Some(answer),
+            elapsed,
+            details,
+            phases,
+        });
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn solve_all(parts: &[Part], rows: &mut Vec<Row>) -> Result<(), Error> {
+    for day in DAYS {
+        solve_day(day, parts, None, rows);
+    }
+
+    Ok(())
+}
+
+// Days are solved concurrently, but their rows still come out in order
+#[cfg(feature = "parallel")]
+fn solve_all(parts: &[Part], rows: &mut Vec<Row>) -> Result<(), Error> {
+    use rayon::prelude::*;
+
+    // day 21 recurses deeply, which overflows the default worker stack in debug builds
+    let pool = rayon::ThreadPoolBuilder::new()
+        .stack_size(64 * 1024 * 1024)
+        .build()?;
+
+    let day_rows = pool.install(|| {
+        DAYS.par_iter()
+            .map(|day| {
+                let mut rows = Vec::new();
+                solve_day(day, parts, None, &mut rows);
+                rows
+            })
+            .collect::<Vec<_>>()
+    });
+
+    rows.extend(day_rows.into_iter().flatten());
+    Ok(())
+}
+
+// Errors are listed after the table, so a long one doesn't stretch the answer column
+fn print_table(rows: &[Row]) {
+    let answers = rows
+        .iter()
+        .map(|row| match &row.answer {
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(_)) => "error".to_owned(),
+            None => "no input".to_owned(),
+        })
+        .collect::<Vec<_>>();
+
+    let answer_width = answers
+        .iter()
+        .flat_map(|answer| answer.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("Day  Part  {:answer_width$}  Time", "Answer");
+    println!("---  ----  {:-<answer_width$}  ----", "");
+
+    for (row, answer) in rows.iter().zip(&answers) {
+        // multi-line answers (like day 10's CRT) continue on rows of their own
+        for (i, line) in answer.lines().enumerate() {
+            if i == 0 {
+                let elapsed = format!("{:.2?}", row.elapsed);
+                println!(
+                    "{:>3}  {:>4}  {line:answer_width$}  {elapsed}",
+                    row.day, row.part
+                );
+            } else {
+                println!("{:3}  {:4}  {line}", "", "");
+            }
+        }
+    }
+
+    let errors = rows
+        .iter()
+        .filter_map(|row| match &row.answer {
+            Some(Err(err)) => Some((row, err)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        println!();
+    }
+
+    for (row, err) in errors {
+        println!("Day {} part {}: {err:#}", row.day, row.part);
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+fn print_profile(rows: &[Row]) {
+    println!();
+    println!(
+        "Day  Part  {:10}  {:>10}  {:>11}  {:>10}",
+        "Phase", "Time", "Allocations", "Peak"
+    );
+    println!("---  ----  ----------  ----------  -----------  ----------");
+
+    for row in rows {
+        for phase in &row.phases {
+            let elapsed = format!("{:.2?}", phase.elapsed);
+            let allocations = phase.allocations.map_or("-".to_owned(), |n| n.to_string());
+            let peak = phase.peak_bytes.map_or("-".to_owned(), format_bytes);
+            println!(
+                "{:>3}  {:>4}  {:10}  {elapsed:>10}  {allocations:>11}  {peak:>10}",
+                row.day, row.part, phase.name
+            );
+        }
+    }
+}
+
+fn print_json(rows: &[Row], profile: bool) {
+    // days with no input have nothing to report
+    for row in rows {
+        let Some(answer) = &row.answer else {
+            continue;
+        };
+
+        let report = Report {
+            profile: profile.then_some(&row.phases[..]),
+            ..Report::new(row.day, row.part, answer, row.elapsed, &row.details)
+        };
+        println!("{}", report.to_json());
+    }
+}
+
+fn main() -> Result<(), Error> {
+    let cli = Cli::parse();
+    cli.log.init()?;
+    cli.images.init()?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            format,
+            profile,
+        } => {
+            let mut rows = Vec::new();
+
+            if all {
+                solve_all(part.parts(), &mut rows)?;
+            } else if let Some(day) = day {
+                solve_day(aoc::find_day(day)?, part.parts(), input, &mut rows);
+            }
+
+            match format {
+                Format::Text => {
+                    print_table(&rows);
+                    if profile {
+                        print_profile(&rows);
+                    }
+                }
+                Format::Json => print_json(&rows, profile),
+            }
+
+            let num_failed = rows
+                .iter()
+                .filter(|row| matches!(row.answer, Some(Err(_))))
+                .count();
+            if num_failed > 0 {
+                bail!("{num_failed} part(s) failed");
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            history,
+        } => {
+            if cfg!(debug_assertions) {
+                eprintln!(
+                    "warning: benchmarking a debug build; pass --release for meaningful numbers"
+                );
+            }
+
+            let history = history.unwrap_or_else(|| {
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("../../bench-history.csv")
+            });
+
+            let days = match day {
+                Some(day) => vec![aoc::find_day(day)?],
+                None => DAYS.iter().collect(),
+            };
+
+            let mut records = Vec::new();
+            for day in days {
+                bench::bench_day(day, part.parts(), iterations, &mut records);
+            }
+
+            bench::report(&history, iterations, &records)?;
+        }
+        Command::Step {
+            day,
+            part,
+            input,
+            delay,
+            history,
+        } => {
+            let day = aoc::find_day(day)?;
+            let input_path = input.unwrap_or_else(|| day.input_path("input01.txt"));
+            let input = read_to_string(&input_path)
+                .map_err(|err| anyhow!("{}: {err}", input_path.display()))?;
+
+            let delay = Duration::from_millis(delay);
+            step::step_day(day, part.parts(), input, delay, history)?;
+        }
+        Command::Verify {
+            day,
+            part,
+            input,
+            record,
+            update,
+            answers,
+        } => {
+            let days = match day {
+                Some(day) => vec![aoc::find_day(day)?],
+                None => DAYS.iter().collect(),
+            };
+
+            let answers = answers.unwrap_or_else(Answers::default_path);
+            verify::verify(
+                &days,
+                part.parts(),
+                input.as_deref(),
+                &answers,
+                record,
+                update,
+            )?;
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let input = generate::generate(day, seed, size)?;
+
+            match output {
+                Some(path) => {
+                    write(&path, input).map_err(|err| anyhow!("{}: {err}", path.display()))?
+                }
+                None => print!("{input}"),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use anyhow::{anyhow, bail, Error};
+use aoc::Day;
+use common::{step, Answer, Part, Step};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::VecDeque;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const KEYS: &str =
+    "space play/pause  ←/→ step  PgUp/PgDn ±100  Home/End  N⏎ seek  +/- speed  wasd/WASD pan  q quit";
+
+// Viewer
+
+struct Viewer {
+    receiver: Option<Receiver<Step>>, // None once the solver has finished
+    steps: VecDeque<Step>,
+    first: usize, // number of the oldest step kept, once history starts dropping them
+    history: usize,
+    pos: usize,
+    playing: bool,
+    delay: Duration,
+    next_tick: Instant,
+    scroll: (u16, u16),
+    seek: String,
+}
+
+impl Viewer {
+    fn len(&self) -> usize {
+        self.first + self.steps.len()
+    }
+
+    // Waits for the solver's next step, returning false if there are no more
+    fn pull(&mut self) -> bool {
+        let Some(receiver) = &self.receiver else {
+            return false;
+        };
+
+        match receiver.recv() {
+            Ok(step) => {
+                self.steps.push_back(step);
+                if self.steps.len() > self.history {
+                    self.steps.pop_front();
+                    self.first += 1;
+                }
+                true
+            }
+            Err(_) => {
+                self.receiver = None;
+                false
+            }
+        }
+    }
+
+    // Moves to step pos, as far as the solver and the history allow
+    fn seek_to(&mut self, pos: usize) {
+        while pos >= self.len() && self.pull() {}
+        self.pos = pos.clamp(self.first, self.len().saturating_sub(1));
+    }
+
+    fn seek_by(&mut self, delta: isize) {
+        self.seek_to(self.pos.saturating_add_signed(delta));
+    }
+
+    fn tick(&mut self) {
+        if self.playing && Instant::now() >= self.next_tick {
+            let pos = self.pos;
+            self.seek_by(1);
+
+            // stop at the end rather than spinning
+            if self.pos == pos {
+                self.playing = false;
+            }
+
+            self.next_tick = Instant::now() + self.delay;
+        }
+    }
+
+    // Returns false to quit
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if ctrl => return false,
+            KeyCode::Char(' ') => {
+                self.playing = !self.playing;
+                self.next_tick = Instant::now();
+            }
+            KeyCode::Right => self.seek_by(1),
+            KeyCode::Left => self.seek_by(-1),
+            KeyCode::PageDown => self.seek_by(100),
+            KeyCode::PageUp => self.seek_by(-100),
+            KeyCode::Home => self.seek_to(0),
+            KeyCode::End => self.seek_to(usize::MAX),
+            KeyCode::Char(ch @ '0'..='9') => self.seek.push(ch),
+            KeyCode::Backspace => {
+                self.seek.pop();
+            }
+            KeyCode::Enter => {
+                // steps are numbered from 1 on screen
+                if let Ok(number) = self.seek.parse::<usize>() {
+                    self.seek_to(number.saturating_sub(1));
+                }
+                self.seek.clear();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.delay = (self.delay / 2).max(Duration::from_millis(1))
+            }
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(10)),
+            // boards like day 14's floor can be thousands of columns wide, hence the big jumps
+            KeyCode::Char('w') => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            KeyCode::Char('s') => self.scroll.0 = self.scroll.0.saturating_add(1),
+            KeyCode::Char('a') => self.scroll.1 = self.scroll.1.saturating_sub(1),
+            KeyCode::Char('d') => self.scroll.1 = self.scroll.1.saturating_add(1),
+            KeyCode::Char('W') => self.scroll.0 = self.scroll.0.saturating_sub(20),
+            KeyCode::Char('S') => self.scroll.0 = self.scroll.0.saturating_add(20),
+            KeyCode::Char('A') => self.scroll.1 = self.scroll.1.saturating_sub(100),
+            KeyCode::Char('D') => self.scroll.1 = self.scroll.1.saturating_add(100),
+            _ => (),
+        }
+
+        true
+    }
+
+    fn draw(&self, frame: &mut Frame, title: &str) {
+        let [board_area, status_area, keys_area] = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let (label, board) = match self.steps.get(self.pos - self.first) {
+            Some(step) => (step.label.as_str(), step.board.as_str()),
+            None => ("", "(no steps)"),
+        };
+
+        let block = Block::bordered().title(format!("{title}: {label}"));
+        let board = Paragraph::new(board).block(block).scroll(self.scroll);
+        frame.render_widget(board, board_area);
+
+        let total = match self.receiver {
+            Some(_) => format!("{}+", self.len()),
+            None => self.len().to_string(),
+        };
+        let state = if self.playing { "playing" } else { "paused" };
+        let seek = if self.seek.is_empty() {
+            String::new()
+        } else {
+            format!("  seek to {}", self.seek)
+        };
+        let status = format!(
+            "step {} of {total}  {state} every {:?}{seek}",
+            self.pos + 1,
+            self.delay
+        );
+        frame.render_widget(Paragraph::new(status), status_area);
+        frame.render_widget(Paragraph::new(KEYS), keys_area);
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal, title: &str) -> Result<(), Error> {
+        loop {
+            self.tick();
+            terminal.draw(|frame| self.draw(frame, title))?;
+
+            let timeout = if self.playing {
+                self.next_tick.saturating_duration_since(Instant::now())
+            } else {
+                Duration::from_secs(60)
+            };
+
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !self.handle_key(key) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+// step_day
+
+// Solves the parts on another thread, showing each step of the simulation as the solver takes it
+pub fn step_day(
+    day: &Day,
+    parts: &[Part],
+    input: String,
+    delay: Duration,
+    history: usize,
+) -> Result<(), Error> {
+    let (sender, receiver) = sync_channel(0);
+    step::set_sink(Some(sender));
+
+    let day = *day;
+    let parts = parts.to_vec();
+    let worker = thread::spawn(move || {
+        let answers = parts
+            .iter()
+            .map(|part| (*part, day.solve(*part, &input)))
+            .collect::<Vec<_>>();
+
+        // hanging up tells the viewer there are no more steps
+        step::set_sink(None);
+        answers
+    });
+
+    let mut viewer = Viewer {
+        receiver: Some(receiver),
+        steps: VecDeque::new(),
+        first: 0,
+        history: history.max(1),
+        pos: 0,
+        playing: false,
+        delay,
+        next_tick: Instant::now(),
+        scroll: (0, 0),
+        seek: String::new(),
+    };
+    viewer.seek_to(0);
+
+    let title = format!("Day {}", day.number);
+    let mut terminal = ratatui::init();
+    let result = viewer.run(&mut terminal, &title);
+    ratatui::restore();
+    result?;
+
+    // quitting early hangs up on the solver, which stops at its next step
+    let finished = viewer.receiver.is_none();
+    drop(viewer);
+
+    let answers = worker
+        .join()
+        .map_err(|_| anyhow!("Day {} panicked", day.number))?;
+
+    if !finished {
+        return Ok(());
+    }
+
+    let mut num_failed = 0;
+    for (part, answer) in answers {
+        match answer {
+            Ok(Answer::Text(text)) if text.contains('\n') => println!("Part {part}:\n{text}"),
+            Ok(answer) => println!("Part {part}: {answer}"),
+            Err(err) => {
+                eprintln!("Part {part}: error: {err:#}");
+                num_failed += 1;
+            }
+        }
+    }
+
+    if num_failed > 0 {
+        bail!("{num_failed} part(s) failed");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use anyhow::{anyhow, bail, Error};
+use aoc::answers::Answers;
+use aoc::Day;
+use common::{Answer, Part};
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+
+enum Status {
+    Pass,
+    Fail(Answer), // what was expected
+    Unknown,
+    NotApplicable, // the part has nothing to solve, so there's no answer to store
+    Recorded,
+    Updated(Answer), // what used to be expected
+    Error(Error),
+}
+
+struct Check {
+    day: u32,
+    part: Part,
+    input: String,
+    answer: Option<Answer>,
+    status: Status,
+}
+
+// The puzzle inputs bundled with a day, like input01.txt and input02.txt
+fn input_names(day: &Day) -> Result<Vec<String>, Error> {
+    let dir = day.input_path("");
+    let mut names = Vec::new();
+
+    for entry in read_dir(&dir).map_err(|err| anyhow!("{}: {err}", dir.display()))? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with("input") && name.ends_with(".txt") {
+            names.push(name);
+        }
+    }
+
+    names.sort();
+    Ok(names)
+}
+
+fn check(day: &Day, part: Part, input_name: &str, input: &str, answers: &Answers) -> Check {
+    let result = day.solve(part, input);
+    let expected = answers.get(day.number, part, input_name);
+
+    let (answer, status) = match (result, expected) {
+        (Ok(Answer::NotApplicable), _) => (Some(Answer::NotApplicable), Status::NotApplicable),
+        (Ok(answer), Some(expected)) if answer == *expected => (Some(answer), Status::Pass),
+        (Ok(answer), Some(expected)) => (Some(answer), Status::Fail(expected.clone())),
+        (Ok(answer), None) => (Some(answer), Status::Unknown),
+        (Err(err), _) => (None, Status::Error(err)),
+    };
+
+    Check {
+        day: day.number,
+        part,
+        input: input_name.to_owned(),
+        answer,
+        status,
+    }
+}
+
+// Lines of expected and actual, marking the ones that differ with - and +
+fn diff(expected: &Answer, actual: &Answer) -> Vec<String> {
+    let expected = expected.to_string();
+    let actual = actual.to_string();
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => lines.push(format!("  {e}")),
+            (e, a) => {
+                lines.extend(e.map(|e| format!("- {e}")));
+                lines.extend(a.map(|a| format!("+ {a}")));
+            }
+        }
+    }
+
+    lines
+}
+
+fn print_check(check: &Check) {
+    let (status, lines) = match &check.status {
+        Status::Pass => ("pass", vec![]),
+        Status::Unknown => ("unknown", vec![]),
+        Status::NotApplicable => ("n/a", vec![]),
+        Status::Recorded => ("recorded", vec![]),
+        Status::Fail(expected) | Status::Updated(expected) => {
+            let status = match check.status {
+                Status::Fail(_) => "FAIL",
+                _ => "updated",
+            };
+            let actual = check.answer.as_ref().expect("Failed checks have an answer");
+
+            if expected.to_string().contains('\n') || actual.to_string().contains('\n') {
+                (status, diff(expected, actual))
+            } else {
+                (status, vec![format!("- {expected}"), format!("+ {actual}")])
+            }
+        }
+        Status::Error(err) => ("error", vec![format!("{err:#}")]),
+    };
+
+    // answers are shown whole unless there's a diff to show instead
+    let lines = match (&check.answer, lines.is_empty()) {
+        (Some(answer), true) => answer.to_string().lines().map(str::to_owned).collect(),
+        _ => lines,
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        if i == 0 {
+            println!(
+                "{:>3}  {:>4}  {:12}  {status:8}  {line}",
+                check.day, check.part, check.input
+            );
+        } else {
+            println!("{:3}  {:4}  {:12}  {:8}  {line}", "", "", "", "");
+        }
+    }
+}
+
+// Solves each day's bundled inputs, comparing against the answers store
+//
+// With record, answers that weren't in the store are added to it; with update as well, ones that differ replace what
+// was there.
+pub fn verify(
+    days: &[&Day],
+    parts: &[Part],
+    input_name: Option<&str>,
+    answers_path: &Path,
+    record: bool,
+    update: bool,
+) -> Result<(), Error> {
+    let mut answers = if answers_path.exists() {
+        Answers::load(answers_path)?
+    } else {
+        Answers::default()
+    };
+
+    println!("Day  Part  Input         Status    Answer");
+    println!("---  ----  ------------  --------  ------");
+
+    let mut checks = Vec::new();
+
+    for day in days {
+        let names = match input_name {
+            Some(name) => vec![name.to_owned()],
+            None => input_names(day)?,
+        };
+
+        for name in names {
+            let path = day.input_path(&name);
+            let input = read_to_string(&path).map_err(|err| anyhow!("{}: {err}", path.display()));
+
+            for part in parts {
+                let mut check = match &input {
+                    Ok(input) => check(day, *part, &name, input, &answers),
+                    Err(err) => Check {
+                        day: day.number,
+                        part: *part,
+                        input: name.clone(),
+                        answer: None,
+                        status: Status::Error(anyhow!("{err}")),
+                    },
+                };
+
+                if let Some(answer) = &check.answer {
+                    let status = std::mem::replace(&mut check.status, Status::Pass);
+                    check.status = match status {
+                        Status::Unknown if record => Status::Recorded,
+                        Status::Fail(expected) if record && update => Status::Updated(expected),
+                        status => status,
+                    };
+
+                    if matches!(check.status, Status::Recorded | Status::Updated(_)) {
+                        answers.insert(day.number, *part, &name, answer.clone());
+                    }
+                }
+
+                print_check(&check);
+                checks.push(check);
+            }
+        }
+    }
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|check| f(&check.status)).count();
+    let passed = count(|status| matches!(status, Status::Pass));
+    let failed = count(|status| matches!(status, Status::Fail(_)));
+    let unknown = count(|status| matches!(status, Status::Unknown));
+    let not_applicable = count(|status| matches!(status, Status::NotApplicable));
+    let changed = count(|status| matches!(status, Status::Recorded | Status::Updated(_)));
+    let errors = count(|status| matches!(status, Status::Error(_)));
+
+    println!();
+    println!(
+        "{passed} passed, {failed} failed, {unknown} unknown, {not_applicable} n/a, {changed} recorded, {errors} errors"
+    );
+
+    if changed > 0 {
+        answers.save(answers_path)?;
+    }
+
+    if unknown > 0 {
+        println!(
+            "Check the unknown answers by hand, then save them with --record \
+             (--record --update replaces failing ones)"
+        );
+    }
+
+    if failed + errors > 0 {
+        bail!("{} check(s) failed", failed + errors);
+    }
+
+    Ok(())
+}
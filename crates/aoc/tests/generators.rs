@@ -0,0 +1,69 @@
+use common::Part;
+
+// Generated inputs parse, whatever the seed and size
+macro_rules! generated_inputs_parse {
+    ($($number:literal => $krate:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $krate() {
+                for size in [1, 2, 10, 100] {
+                    for seed in 0..10 {
+                        let input = generate::generate($number, seed, size).unwrap();
+                        if let Err(err) = $krate::parse(&input) {
+                            panic!("seed {seed}, size {size}: {err}\n{input}");
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mod parse {
+    generated_inputs_parse! {
+        1 => day01, 2 => day02, 3 => day03, 4 => day04, 5 => day05,
+        6 => day06, 7 => day07, 8 => day08, 9 => day09, 10 => day10,
+        11 => day11, 12 => day12, 13 => day13, 14 => day14, 15 => day15,
+        16 => day16, 17 => day17, 18 => day18, 19 => day19, 20 => day20,
+        21 => day21, 22 => day22, 23 => day23, 24 => day24, 25 => day25,
+    }
+}
+
+#[test]
+fn every_day_has_a_generator() {
+    let days = generate::days().collect::<Vec<_>>();
+    for day in aoc::DAYS {
+        assert!(
+            days.contains(&day.number),
+            "day {} has no generator",
+            day.number
+        );
+    }
+}
+
+// Beyond parsing, generated inputs have answers, for the days that guarantee one
+#[test]
+fn generated_inputs_solve() {
+    // day 11's part 1 worry levels can outgrow a u64 when monkeys pass items around at random, day 15's part 2 gap
+    // isn't guaranteed, and day 17's part 2 isn't finished
+    let unsolvable = [(11, Part::One), (15, Part::Two), (17, Part::Two)];
+
+    for day in aoc::DAYS {
+        for seed in 0..3 {
+            let input = generate::generate(day.number, seed, 50).unwrap();
+
+            for part in [Part::One, Part::Two] {
+                if unsolvable.contains(&(day.number, part)) {
+                    continue;
+                }
+
+                if let Err(err) = day.solve(part, &input) {
+                    panic!(
+                        "day {} part {part}, seed {seed}: {err:#}\n{input}",
+                        day.number
+                    );
+                }
+            }
+        }
+    }
+}
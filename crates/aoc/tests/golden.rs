@@ -0,0 +1,64 @@
+use aoc::answers::Answers;
+use common::Part;
+use std::fs::read_to_string;
+
+// day 21 recurses deeply with large fractions, which overflows the default test thread stack in debug builds
+fn with_big_stack<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn golden_answers() {
+    with_big_stack(check_golden_answers);
+}
+
+fn check_golden_answers() {
+    let answers = Answers::load(&Answers::default_path()).unwrap();
+    let mut failures = Vec::new();
+
+    for expected in answers.entries() {
+        let day = aoc::find_day(expected.day).unwrap();
+        let part = expected.part().unwrap();
+
+        let input = read_to_string(day.input_path(&expected.input)).unwrap();
+
+        match day.solve(part, &input) {
+            Ok(answer) if answer == expected.answer => (),
+            Ok(answer) => failures.push(format!(
+                "day {} part {part} {}: expected {}, got {answer}",
+                expected.day, expected.input, expected.answer
+            )),
+            Err(err) => failures.push(format!(
+                "day {} part {part} {}: {err:#}",
+                expected.day, expected.input
+            )),
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+// The example's row and search area, given outright, agree with the stored answers too
+#[test]
+fn golden_day15_example() {
+    let answers = Answers::load(&Answers::default_path()).unwrap();
+    let day = aoc::find_day(15).unwrap();
+    let input = read_to_string(day.input_path("input01.txt")).unwrap();
+
+    assert_eq!(
+        Some(&day15::part1_with(&input, day15::EXAMPLE_DISTRESS_Y).unwrap()),
+        answers.get(15, Part::One, "input01.txt")
+    );
+    assert_eq!(
+        Some(&day15::part2_with(&input, day15::EXAMPLE_MAX_VAL).unwrap()),
+        answers.get(15, Part::Two, "input01.txt")
+    );
+}
@@ -0,0 +1,692 @@
+use day13::Value;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use std::collections::{HashMap, HashSet};
+
+// Hostile input
+
+// Characters that mean something to at least one of the parsers
+const PALETTE: &[char] = &[
+    '0', '1', '9', '-', '+', '*', '/', ',', ':', ';', '=', '[', ']', '(', ')', '<', '>', '$', ' ',
+    '\n', 'a', 'z', 'A', 'Z', 'S', 'E', 'v', 'x', 'é', '\u{0}',
+];
+
+#[derive(Clone, Debug)]
+enum Edit {
+    Insert(Index, char),
+    Replace(Index, char),
+    Delete(Index),
+    DuplicateLine(Index),
+    InsertNumber(Index, String),
+    Truncate(Index),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    let ch = prop_oneof![4 => prop::sample::select(PALETTE), 1 => any::<char>()];
+    let number = prop_oneof![
+        Just("0".to_owned()),
+        Just("-0".to_owned()),
+        Just("4294967296".to_owned()),
+        Just("-9223372036854775809".to_owned()),
+        "[0-9]{1,40}",
+    ];
+
+    prop_oneof![
+        3 => (any::<Index>(), ch.clone()).prop_map(|(idx, ch)| Edit::Insert(idx, ch)),
+        3 => (any::<Index>(), ch).prop_map(|(idx, ch)| Edit::Replace(idx, ch)),
+        3 => any::<Index>().prop_map(Edit::Delete),
+        1 => any::<Index>().prop_map(Edit::DuplicateLine),
+        2 => (any::<Index>(), number).prop_map(|(idx, number)| Edit::InsertNumber(idx, number)),
+        1 => any::<Index>().prop_map(Edit::Truncate),
+    ]
+}
+
+fn apply(text: &mut Vec<char>, edit: &Edit) {
+    if text.is_empty() {
+        return;
+    }
+
+    match edit {
+        Edit::Insert(idx, ch) => {
+            let pos = idx.index(text.len() + 1);
+            text.insert(pos, *ch);
+        }
+        Edit::Replace(idx, ch) => {
+            let pos = idx.index(text.len());
+            text[pos] = *ch;
+        }
+        Edit::Delete(idx) => {
+            let pos = idx.index(text.len());
+            text.remove(pos);
+        }
+        Edit::DuplicateLine(idx) => {
+            let pos = idx.index(text.len());
+            let start = text[..pos]
+                .iter()
+                .rposition(|ch| *ch == '\n')
+                .map_or(0, |i| i + 1);
+            let end = text[pos..]
+                .iter()
+                .position(|ch| *ch == '\n')
+                .map_or(text.len(), |i| pos + i + 1);
+            let line = text[start..end].to_vec();
+            text.splice(end..end, line);
+        }
+        Edit::InsertNumber(idx, number) => {
+            let pos = idx.index(text.len() + 1);
+            text.splice(pos..pos, number.chars());
+        }
+        Edit::Truncate(idx) => {
+            let len = idx.index(text.len());
+            text.truncate(len);
+        }
+    }
+}
+
+// A day's example input with a few random edits, or text with no relation to it at all
+fn hostile(example: &'static str) -> impl Strategy<Value = String> {
+    let mutated = prop::collection::vec(edit(), 1..8).prop_map(move |edits| {
+        let mut text = example.chars().collect::<Vec<_>>();
+        for edit in &edits {
+            apply(&mut text, edit);
+        }
+        text.into_iter().collect()
+    });
+
+    prop_oneof![4 => mutated, 1 => any::<String>()]
+}
+
+// Parsers return errors, rather than panicking, on whatever they're given
+macro_rules! parsers_never_panic {
+    ($($krate:ident),* $(,)?) => {
+        proptest! {
+            $(
+                #[test]
+                fn $krate(input in hostile(include_str!(concat!("../../", stringify!($krate), "/input01.txt")))) {
+                    let _ = $krate::parse(&input);
+                }
+            )*
+        }
+    };
+}
+
+mod never_panic {
+    use super::*;
+
+    parsers_never_panic! {
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+        day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+        day21, day22, day23, day24, day25,
+    }
+
+    #[test]
+    fn day13_deep_nesting() {
+        let depth = 1_000_000;
+        let input = format!("{}{}\n[]\n", "[".repeat(depth), "]".repeat(depth));
+        assert!(day13::parse(&input).is_err());
+    }
+
+    #[test]
+    fn day05_stray_lines() {
+        for input in [
+            "[A] junk\n 1   2\n\nmove 1 from 1 to 2\n",
+            "[A] [B]\n 1   2\nmove 1 from 1 to 2\n",
+        ] {
+            assert!(day05::parse(input).is_err(), "{input:?}");
+        }
+    }
+}
+
+// Solvers return errors, rather than panicking, on whatever parses
+//
+// How long a solver takes can grow with the numbers in its input, like day 9's step counts, so inputs with large ones
+// are left to the fuzz targets.
+macro_rules! solvers_never_panic {
+    ($($krate:ident),* $(,)?) => {
+        proptest! {
+            $(
+                #[test]
+                fn $krate(input in hostile(include_str!(concat!("../../", stringify!($krate), "/input01.txt")))) {
+                    let large_number = input
+                        .split(|ch: char| !ch.is_ascii_digit())
+                        .any(|digits| digits.len() > 4);
+                    if !large_number && $krate::parse(&input).is_ok() {
+                        let _ = $krate::part1(&input);
+                        let _ = $krate::part2(&input);
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mod solvers {
+    use super::*;
+
+    solvers_never_panic! {
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+        day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+        day21, day22, day23, day24, day25,
+    }
+}
+
+// Round trips
+//
+// Each input is written out from what it should parse to, or for days whose inputs have to hang together (a
+// directory tree, a map with a start and an end), taken from the day's generator; either way, what's parsed is
+// written back out and compared.
+
+// Rows of the same width, each cell picked from cells
+fn grid_rows(cells: &'static [char]) -> impl Strategy<Value = Vec<String>> {
+    (1..10usize, 1..10usize).prop_flat_map(move |(width, height)| {
+        let row = prop::collection::vec(prop::sample::select(cells), width);
+        prop::collection::vec(row.prop_map(String::from_iter), height)
+    })
+}
+
+fn lines_text(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+fn direction_char(dir: geom::Direction) -> char {
+    match dir {
+        geom::Direction::Up => 'U',
+        geom::Direction::Right => 'R',
+        geom::Direction::Down => 'D',
+        geom::Direction::Left => 'L',
+    }
+}
+
+fn day11_text(monkeys: &[(Vec<u64>, String, u64, u64, u64)]) -> String {
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(idx, (items, operation, divisor, if_true, if_false))| {
+            let items = items
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Monkey {idx}:\n  Starting items: {items}\n  Operation: new = {operation}\n  \
+                 Test: divisible by {divisor}\n    If true: throw to monkey {if_true}\n    \
+                 If false: throw to monkey {if_false}\n"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day11_operand(operand: &day11::Operand) -> String {
+    match operand {
+        day11::Operand::Old => "old".to_owned(),
+        day11::Operand::Const(value) => value.to_string(),
+    }
+}
+
+fn day13_value() -> impl Strategy<Value = Value> {
+    let leaf = any::<i32>().prop_map(Value::Integer);
+    leaf.prop_recursive(6, 64, 8, |inner| {
+        prop::collection::vec(inner, 0..8).prop_map(Value::List)
+    })
+}
+
+fn day13_text(value: &Value) -> String {
+    match value {
+        Value::Integer(i) => i.to_string(),
+        Value::List(items) => format!(
+            "[{}]",
+            items.iter().map(day13_text).collect::<Vec<_>>().join(",")
+        ),
+    }
+}
+
+// A rock path, turning only at right angles
+fn day14_path() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    (
+        any::<(i32, i32)>(),
+        prop::collection::vec((any::<bool>(), any::<i32>()), 0..6),
+    )
+        .prop_map(|(start, turns)| {
+            let mut path = vec![start];
+            for (horizontal, to) in turns {
+                let (x, y) = *path.last().unwrap();
+                path.push(if horizontal { (to, y) } else { (x, to) });
+            }
+            path
+        })
+}
+
+proptest! {
+    #[test]
+    fn day01_round_trip(elves in prop::collection::vec(prop::collection::vec(any::<u32>(), 1..6), 1..6)) {
+        let input = elves
+            .iter()
+            .map(|calories| calories.iter().map(u32::to_string).collect::<Vec<_>>().join("\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        prop_assert_eq!(day01::parse(&input)?, elves);
+    }
+
+    #[test]
+    fn day02_round_trip(rounds in prop::collection::vec("[ABC] [XYZ]", 1..20)) {
+        let input = lines_text(rounds.clone());
+
+        let parsed = day02::parse(&input)?
+            .into_iter()
+            .map(|(theirs, ours)| {
+                let theirs = match theirs {
+                    day02::Shape::Rock => 'A',
+                    day02::Shape::Paper => 'B',
+                    day02::Shape::Scissors => 'C',
+                };
+                format!("{theirs} {ours}")
+            })
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, rounds);
+    }
+
+    #[test]
+    fn day03_round_trip(rucksacks in prop::collection::vec("([a-zA-Z]{2}){1,20}", 1..20)) {
+        let input = lines_text(rucksacks.clone());
+        prop_assert_eq!(day03::parse(&input)?, rucksacks);
+    }
+
+    #[test]
+    fn day04_round_trip(pairs in prop::collection::vec(any::<[u32; 4]>(), 1..10)) {
+        let input = pairs
+            .iter()
+            .map(|[a, b, c, d]| format!("{a}-{b},{c}-{d}\n"))
+            .collect::<String>();
+
+        let expected = pairs.iter().map(|[a, b, c, d]| (*a..=*b, *c..=*d)).collect::<Vec<_>>();
+        prop_assert_eq!(day04::parse(&input)?, expected);
+    }
+
+    #[test]
+    fn day05_round_trip(
+        stacks in prop::collection::vec("[A-Z]{0,6}", 1..10)
+            .prop_filter("last stack has a crate", |stacks| !stacks.last().unwrap().is_empty()),
+        moves in prop::collection::vec((any::<u16>(), any::<Index>(), any::<Index>()), 0..10),
+    ) {
+        let height = stacks.iter().map(String::len).max().unwrap();
+        let mut input = String::new();
+
+        for row in (0..height).rev() {
+            let cells = stacks
+                .iter()
+                .map(|stack| match stack.as_bytes().get(row) {
+                    Some(ch) => format!("[{}]", *ch as char),
+                    None => "   ".to_owned(),
+                })
+                .collect::<Vec<_>>();
+            input.push_str(&cells.join(" "));
+            input.push('\n');
+        }
+
+        let numbers = (1..=stacks.len()).map(|n| format!(" {n} ")).collect::<Vec<_>>();
+        input.push_str(&numbers.join(" "));
+        input.push_str("\n\n");
+
+        let moves = moves
+            .iter()
+            .map(|(count, from, to)| (*count as usize, from.index(stacks.len()) + 1, to.index(stacks.len()) + 1))
+            .collect::<Vec<_>>();
+
+        for (count, from, to) in &moves {
+            input.push_str(&format!("move {count} from {from} to {to}\n"));
+        }
+
+        let plan = day05::parse(&input)?;
+        prop_assert_eq!(&plan.stacks, &stacks);
+
+        let parsed_moves = plan
+            .instructions
+            .iter()
+            .map(|instr| (instr.count, instr.stack_from, instr.stack_to))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed_moves, moves);
+    }
+
+    #[test]
+    fn day06_round_trip(buffers in prop::collection::vec("[a-zA-Z]{1,30}", 1..5)) {
+        let input = lines_text(buffers.clone());
+        let expected = buffers.iter().map(String::as_bytes).collect::<Vec<_>>();
+        prop_assert_eq!(day06::parse(&input)?, expected);
+    }
+
+    // every file listed comes back, in order, with its size and a path ending in its name
+    #[test]
+    fn day07_files(seed in any::<u64>(), size in 1..50usize) {
+        let input = generate::generate(7, seed, size).unwrap();
+
+        let listed = input
+            .lines()
+            .filter(|line| !line.starts_with('$') && !line.starts_with("dir "))
+            .map(|line| line.to_owned())
+            .collect::<Vec<_>>();
+        let parsed = day07::parse(&input)?
+            .iter()
+            .map(|entry| format!("{} {}", entry.size, entry.path.file_name().unwrap().to_string_lossy()))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, listed);
+    }
+
+    #[test]
+    fn day08_round_trip(rows in grid_rows(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'])) {
+        let input = lines_text(rows.clone());
+
+        let parsed = day08::parse(&input)?
+            .rows()
+            .map(|row| row.iter().map(|height| char::from(b'0' + height - 1)).collect::<String>())
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, rows);
+    }
+
+    #[test]
+    fn day09_round_trip(motions in prop::collection::vec(("[LRUD]", any::<u32>()), 1..20)) {
+        let motions = motions.into_iter().map(|(dir, count)| format!("{dir} {count}")).collect::<Vec<_>>();
+        let input = lines_text(motions.clone());
+
+        let parsed = day09::parse(&input)?
+            .into_iter()
+            .map(|(dir, count)| format!("{} {count}", direction_char(dir)))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, motions);
+    }
+
+    #[test]
+    fn day10_round_trip(values in prop::collection::vec(prop::option::of(any::<i32>()), 1..20)) {
+        let instrs = values
+            .iter()
+            .map(|value| value.map_or("noop".to_owned(), |value| format!("addx {value}")))
+            .collect::<Vec<_>>();
+        let input = lines_text(instrs.clone());
+
+        let parsed = day10::parse(&input)?
+            .into_iter()
+            .map(|instr| match instr {
+                day10::Instr::Noop => "noop".to_owned(),
+                day10::Instr::Addx(value) => format!("addx {value}"),
+            })
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, instrs);
+    }
+
+    #[test]
+    fn day11_round_trip(
+        monkeys in prop::collection::vec(
+            (
+                prop::collection::vec(any::<u64>(), 1..5),
+                "(old|[1-9][0-9]{0,5}) [+*] (old|[1-9][0-9]{0,5})",
+                1..=u64::MAX,
+                any::<u64>(),
+                any::<u64>(),
+            ),
+            1..5,
+        ),
+    ) {
+        let input = day11_text(&monkeys);
+
+        let parsed = day11::parse(&input)?
+            .into_iter()
+            .map(|monkey| {
+                let oper = match monkey.oper.oper {
+                    day11::Operator::Add => '+',
+                    day11::Operator::Mul => '*',
+                };
+                let operation =
+                    format!("{} {oper} {}", day11_operand(&monkey.oper.left), day11_operand(&monkey.oper.right));
+                (monkey.initial_items, operation, monkey.test_divisor, monkey.true_monkey, monkey.false_monkey)
+            })
+            .collect::<Vec<_>>();
+        prop_assert_eq!(day11_text(&parsed), input);
+    }
+
+    #[test]
+    fn day12_round_trip(seed in any::<u64>(), size in 1..50usize) {
+        let input = generate::generate(12, seed, size).unwrap();
+
+        let map = day12::parse(&input)?;
+        let parsed = map
+            .heights()
+            .iter()
+            .map(|(pos, height)| {
+                let ch = if pos == map.start_pos() {
+                    'S'
+                } else if pos == map.end_pos() {
+                    'E'
+                } else {
+                    char::from(b'a' + height)
+                };
+                (ch, pos.0 == map.heights().width() - 1)
+            })
+            .map(|(ch, end_of_row)| if end_of_row { format!("{ch}\n") } else { ch.to_string() })
+            .collect::<String>();
+        prop_assert_eq!(parsed, input);
+    }
+
+    #[test]
+    fn day13_round_trip(pairs in prop::collection::vec((day13_value(), day13_value()), 1..5)) {
+        let input = pairs
+            .iter()
+            .map(|(left, right)| format!("{}\n{}\n", day13_text(left), day13_text(right)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        prop_assert_eq!(day13::parse(&input)?, pairs);
+    }
+
+    #[test]
+    fn day14_round_trip(paths in prop::collection::vec(day14_path(), 1..10)) {
+        let input = lines_text(paths.iter().map(|path| {
+            path.iter().map(|(x, y)| format!("{x},{y}")).collect::<Vec<_>>().join(" -> ")
+        }));
+
+        let parsed = day14::parse(&input)?
+            .iter()
+            .map(|path| path.iter().map(|point| (point.x, point.y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, paths);
+    }
+
+    #[test]
+    fn day15_round_trip(sensors in prop::collection::vec(any::<[i32; 4]>(), 1..20)) {
+        let input = lines_text(sensors.iter().map(|[sx, sy, bx, by]| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}")
+        }));
+
+        let parsed = day15::parse(&input)?
+            .iter()
+            .map(|sensor| [sensor.sensor.x, sensor.sensor.y, sensor.beacon.x, sensor.beacon.y])
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, sensors);
+    }
+
+    // the valves come back in order of first mention, so the lines are compared in any order
+    #[test]
+    fn day16_round_trip(seed in any::<u64>(), size in 1..50usize) {
+        let input = generate::generate(16, seed, size).unwrap();
+
+        let nodes = day16::parse(&input)?;
+        let mut parsed = nodes
+            .iter()
+            .map(|node| {
+                let leads = node.edges.iter().map(|edge| nodes[*edge as usize].name).collect::<Vec<_>>();
+                let (tunnels, lead, valves) =
+                    if leads.len() == 1 { ("tunnel", "leads", "valve") } else { ("tunnels", "lead", "valves") };
+                format!(
+                    "Valve {} has flow rate={}; {tunnels} {lead} to {valves} {}",
+                    node.name,
+                    node.flow_rate,
+                    leads.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut lines = input.lines().collect::<Vec<_>>();
+
+        parsed.sort();
+        lines.sort();
+        prop_assert_eq!(parsed, lines);
+    }
+
+    #[test]
+    fn day17_round_trip(jets in "[<>]{1,100}") {
+        let parsed = day17::parse(&jets)?
+            .into_iter()
+            .map(|jet| match jet {
+                day17::Move::Left => '<',
+                day17::Move::Right => '>',
+                day17::Move::Down => 'v',
+            })
+            .collect::<String>();
+        prop_assert_eq!(parsed, jets);
+    }
+
+    #[test]
+    fn day18_round_trip(cubes in prop::collection::hash_set(any::<(i16, i16, i16)>(), 1..20)) {
+        let input = cubes.iter().map(|(x, y, z)| format!("{x},{y},{z}\n")).collect::<String>();
+
+        let expected = cubes
+            .iter()
+            .map(|&(x, y, z)| day18::Position { x: x.into(), y: y.into(), z: z.into() })
+            .collect::<HashSet<_>>();
+        prop_assert_eq!(day18::parse(&input)?, expected);
+    }
+
+    #[test]
+    fn day19_round_trip(blueprints in prop::collection::vec((any::<u32>(), any::<[u32; 6]>()), 1..10)) {
+        let input = lines_text(blueprints.iter().map(|(id, [a, b, c, d, e, f])| {
+            format!(
+                "Blueprint {id}: Each ore robot costs {a} ore. Each clay robot costs {b} ore. \
+                 Each obsidian robot costs {c} ore and {d} clay. Each geode robot costs {e} ore and {f} obsidian."
+            )
+        }));
+
+        let expected = blueprints
+            .iter()
+            .map(|&(id, [a, b, c, d, e, f])| day19::Blueprint {
+                id,
+                costs: [[a, 0, 0], [b, 0, 0], [c, d, 0], [e, 0, f]],
+            })
+            .collect::<Vec<_>>();
+        prop_assert_eq!(day19::parse(&input)?, expected);
+    }
+
+    #[test]
+    fn day20_round_trip(numbers in prop::collection::vec(any::<i64>(), 1..20)) {
+        let input = numbers.iter().map(|n| format!("{n}\n")).collect::<String>();
+        prop_assert_eq!(day20::parse(&input)?, numbers);
+    }
+
+    #[test]
+    fn day21_round_trip(
+        jobs in prop::collection::hash_map(
+            "[a-z]{4}",
+            prop_oneof![any::<u64>().prop_map(|value| value.to_string()), "[a-z]{4} [-+*/] [a-z]{4}"],
+            1..20,
+        ),
+    ) {
+        let input = lines_text(jobs.iter().map(|(name, job)| format!("{name}: {job}")));
+
+        let parsed = day21::parse(&input)?
+            .into_iter()
+            .map(|(name, expr)| {
+                let job = match expr {
+                    // a whole number, as every monkey starts out with, debugs as just that
+                    day21::Expr::Const { value } => format!("{value:?}"),
+                    day21::Expr::BinOp { left, right, binop } => {
+                        let binop = match binop {
+                            day21::BinOp::Add => '+',
+                            day21::BinOp::Sub => '-',
+                            day21::BinOp::Mul => '*',
+                            day21::BinOp::Div => '/',
+                        };
+                        format!("{left} {binop} {right}")
+                    }
+                };
+                (name.to_owned(), job)
+            })
+            .collect::<HashMap<_, _>>();
+        prop_assert_eq!(parsed, jobs);
+    }
+
+    // the generator leaves off the void at the end of each row, which the parser pads back out
+    #[test]
+    fn day22_round_trip(seed in any::<u64>(), size in 1..50usize) {
+        let input = generate::generate(22, seed, size).unwrap();
+
+        let notes = day22::parse(&input)?;
+        let board = notes.board.rows().map(|row| {
+            let row = row
+                .iter()
+                .map(|tile| match tile {
+                    day22::Tile::Void => ' ',
+                    day22::Tile::Open => '.',
+                    day22::Tile::Wall => '#',
+                })
+                .collect::<String>();
+            row.trim_end().to_owned()
+        });
+        let path = notes
+            .path
+            .iter()
+            .map(|instr| match instr {
+                day22::Instruction::Forward(count) => count.to_string(),
+                day22::Instruction::TurnLeft => "L".to_owned(),
+                day22::Instruction::TurnRight => "R".to_owned(),
+            })
+            .collect::<String>();
+        prop_assert_eq!(format!("{}\n{path}\n", lines_text(board)), input);
+    }
+
+    #[test]
+    fn day23_round_trip(rows in grid_rows(&['.', '#'])) {
+        let input = lines_text(rows.clone());
+
+        let elves = day23::parse(&input)?;
+        let parsed = (0..rows.len())
+            .map(|y| {
+                (0..rows[0].len())
+                    .map(|x| if elves.0.contains(&geom::Point2::new(x as i32, y as i32)) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        prop_assert_eq!(parsed, rows);
+    }
+
+    #[test]
+    fn day24_round_trip(seed in any::<u64>(), size in 1..50usize) {
+        let input = generate::generate(24, seed, size).unwrap();
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let valley = day24::parse(&input)?;
+        let parsed = valley.cells.rows().map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    day24::Cell::Wall => '#',
+                    day24::Cell::Open => '.',
+                    day24::Cell::Blizzard(dir) => match dir {
+                        geom::Direction::Up => '^',
+                        geom::Direction::Right => '>',
+                        geom::Direction::Down => 'v',
+                        geom::Direction::Left => '<',
+                    },
+                })
+                .collect::<String>()
+        });
+        prop_assert_eq!(lines_text(parsed), input.as_str());
+
+        let start = (lines[0].find('.').unwrap(), 0);
+        let goal = (lines[lines.len() - 1].find('.').unwrap(), lines.len() - 1);
+        prop_assert_eq!((valley.start, valley.goal), (start, goal));
+    }
+
+    #[test]
+    fn day25_round_trip(numbers in prop::collection::vec(any::<i64>(), 1..20)) {
+        let input = numbers.iter().map(|n| format!("{}\n", day25::Snafu::from(*n))).collect::<String>();
+        let expected = numbers.iter().map(|n| day25::Snafu::from(*n)).collect::<Vec<_>>();
+        prop_assert_eq!(day25::parse(&input)?, expected);
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+// CountingAlloc
+
+// The system allocator, counting what passes through it; binaries opt in with #[global_allocator]
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0); // bytes
+static PEAK: AtomicUsize = AtomicUsize::new(0); // bytes, since the innermost measure() began
+
+fn add(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn sub(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        sub(layout.size());
+    }
+
+    // a resize counts as an allocation, since growing a Vec is usually one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            add(new_size);
+            sub(layout.size());
+        }
+        new_ptr
+    }
+}
+
+// Whether CountingAlloc is the global allocator (anything that's run has allocated by now if it is)
+pub fn installed() -> bool {
+    ALLOCATIONS.load(Ordering::Relaxed) > 0
+}
+
+// AllocStats
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub peak_bytes: usize, // the most in use at once, beyond what already was
+}
+
+// Runs f, counting what it allocates
+//
+// Counts cover every thread, so they're only exact when nothing else is running.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let current = CURRENT.load(Ordering::Relaxed);
+    let outer_peak = PEAK.swap(current, Ordering::Relaxed);
+
+    let result = f();
+
+    // an enclosing measure() still needs to see this peak
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        peak_bytes: peak.saturating_sub(current),
+    };
+    (result, stats)
+}
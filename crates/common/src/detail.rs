@@ -0,0 +1,28 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+// Extra facts about how an answer was found (like where something was), keyed by name
+pub type Details = Map<String, Value>;
+
+thread_local! {
+    static RECORDED: RefCell<Option<Details>> = const { RefCell::new(None) };
+}
+
+// Notes a detail of the current solve if the caller is recording
+pub fn detail(name: &str, value: impl Serialize) {
+    RECORDED.with(|recorded| {
+        if let Some(details) = recorded.borrow_mut().as_mut() {
+            let value = serde_json::to_value(value).unwrap_or(Value::Null);
+            details.insert(name.to_owned(), value);
+        }
+    });
+}
+
+// Runs f, returning its result along with the details it noted
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Details) {
+    let outer = RECORDED.with(|recorded| recorded.replace(Some(Map::new())));
+    let result = f();
+    let details = RECORDED.with(|recorded| recorded.replace(outer));
+    (result, details.unwrap_or_default())
+}
@@ -0,0 +1,217 @@
+use anyhow::{anyhow, Error};
+use clap::ValueEnum;
+use std::collections::HashMap;
+use std::fs::{create_dir_all, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+// Image
+
+// An RGB image, stored row-major from the top left
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Out of bounds pixels are ignored
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[x + y * self.width] = color;
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        for y in y..y + height {
+            for x in x..x + width {
+                self.set(x, y, color);
+            }
+        }
+    }
+
+    // Binary PPM (P6), which most image viewers and ffmpeg read
+    pub fn write_ppm(&self, mut writer: impl Write) -> Result<(), Error> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels.concat())?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<(), Error> {
+        let width = u32::try_from(self.width)?;
+        let height = u32::try_from(self.height)?;
+
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path, format: ImageFormat) -> Result<(), Error> {
+        let file = File::create(path).map_err(|err| anyhow!("{}: {err}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+
+        match format {
+            ImageFormat::Png => self.write_png(&mut writer)?,
+            ImageFormat::Ppm => self.write_ppm(&mut writer)?,
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+// Colors
+
+// Black through red and yellow to white as value goes from 0 to max
+pub fn heat(value: usize, max: usize) -> Rgb {
+    let t = if max == 0 {
+        0.0
+    } else {
+        value.min(max) as f64 / max as f64
+    };
+
+    let channel = |start: f64| ((t * 3.0 - start).clamp(0.0, 1.0) * 255.0) as u8;
+    [channel(0.0), channel(1.0), channel(2.0)]
+}
+
+// ImageFormat
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Ppm => "ppm",
+        }
+    }
+}
+
+// Output
+
+struct Output {
+    dir: PathBuf,
+    format: ImageFormat,
+    frame_every: usize,
+    frame_counts: HashMap<String, usize>,
+}
+
+// None until something asks for images
+static OUTPUT: Mutex<Option<Output>> = Mutex::new(None);
+
+// Has solvers write their images into dir, keeping one frame in every frame_every
+pub fn set_output(dir: &Path, format: ImageFormat, frame_every: usize) -> Result<(), Error> {
+    create_dir_all(dir).map_err(|err| anyhow!("{}: {err}", dir.display()))?;
+
+    *OUTPUT.lock().unwrap_or_else(|err| err.into_inner()) = Some(Output {
+        dir: dir.to_owned(),
+        format,
+        frame_every: frame_every.max(1),
+        frame_counts: HashMap::new(),
+    });
+
+    Ok(())
+}
+
+pub fn enabled() -> bool {
+    OUTPUT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .is_some()
+}
+
+// Writes name.png (or .ppm), if images are wanted; f is only called if they are
+pub fn still(name: &str, f: impl FnOnce() -> Image) -> Result<(), Error> {
+    let mut output = OUTPUT.lock().unwrap_or_else(|err| err.into_inner());
+    let Some(output) = output.as_mut() else {
+        return Ok(());
+    };
+
+    let path = output
+        .dir
+        .join(format!("{name}.{}", output.format.extension()));
+    f().save(&path, output.format)
+}
+
+// Writes the next of a numbered sequence of frames, name-00000.png and on
+pub fn frame(name: &str, f: impl FnOnce() -> Image) -> Result<(), Error> {
+    let mut output = OUTPUT.lock().unwrap_or_else(|err| err.into_inner());
+    let Some(output) = output.as_mut() else {
+        return Ok(());
+    };
+
+    let count = output.frame_counts.entry(name.to_owned()).or_insert(0);
+    let idx = *count;
+    *count += 1;
+
+    if idx % output.frame_every != 0 {
+        return Ok(());
+    }
+
+    let path = output.dir.join(format!(
+        "{name}-{:05}.{}",
+        idx / output.frame_every,
+        output.format.extension()
+    ));
+    f().save(&path, output.format)
+}
+
+// ImageArgs
+
+// Command line arguments for writing images of the simulations, for flattening into other Args
+#[derive(Debug, clap::Args)]
+pub struct ImageArgs {
+    /// Write images (and frame sequences) of the days that draw them into this directory
+    #[arg(long, global = true, value_name = "DIR")]
+    pub images: Option<PathBuf>,
+
+    /// Format of the images
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub image_format: ImageFormat,
+
+    /// Keep one frame in every N of a sequence
+    #[arg(long, global = true, value_name = "N", default_value_t = 1)]
+    pub frame_every: usize,
+}
+
+impl ImageArgs {
+    pub fn init(&self) -> Result<(), Error> {
+        match &self.images {
+            Some(dir) => set_output(dir, self.image_format, self.frame_every),
+            None => Ok(()),
+        }
+    }
+}
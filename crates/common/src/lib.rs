@@ -0,0 +1,288 @@
+use anyhow::{ensure, Error};
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::fs;
+use std::io::{read_to_string, stdin};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub mod alloc;
+pub mod detail;
+pub mod image;
+pub mod log;
+pub mod parse;
+pub mod phase;
+pub mod step;
+
+pub use detail::{detail, Details};
+pub use image::ImageArgs;
+pub use log::LogArgs;
+pub use parse::{parse_token, ParseError};
+pub use phase::{phase, Phase};
+pub use step::{step, Step};
+
+// Part
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::One => f.pad("1"),
+            Self::Two => f.pad("2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::One => serializer.serialize_u8(1),
+            Self::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+// PartSelection
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(self) -> &'static [Part] {
+        match self {
+            Self::One => &[Part::One],
+            Self::Two => &[Part::Two],
+            Self::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+// Answer
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    NotApplicable, // for a part with nothing to solve, like day 25's part 2
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Text(text) => f.write_str(text),
+            Self::NotApplicable => f.write_str("n/a"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Int(i64::try_from(value).expect("answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+// Format
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    // one JSON object per answer, per line
+    Json,
+}
+
+// Report
+
+// One answer as written by --format json
+#[derive(Debug, Serialize)]
+pub struct Report<'a> {
+    pub day: u32,
+    pub part: Part,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed: f64, // seconds
+    #[serde(skip_serializing_if = "Details::is_empty")]
+    pub details: &'a Details,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<&'a [Phase]>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(
+        day: u32,
+        part: Part,
+        answer: &'a Result<Answer, Error>,
+        elapsed: Duration,
+        details: &'a Details,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            input: None,
+            answer: answer.as_ref().ok(),
+            error: answer.as_ref().err().map(|err| format!("{err:#}")),
+            elapsed: elapsed.as_secs_f64(),
+            details,
+            profile: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Report serializes to JSON")
+    }
+}
+
+// Args
+
+// Command line arguments shared by every day's binary
+#[derive(Debug, Parser)]
+#[group(skip)]
+pub struct Args {
+    /// Which part of the puzzle to solve
+    #[arg(long, value_enum, default_value_t)]
+    pub part: PartSelection,
+
+    /// How to print answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    #[command(flatten)]
+    pub log: LogArgs,
+
+    #[command(flatten)]
+    pub images: ImageArgs,
+
+    /// Input files to solve in turn (defaults to reading stdin)
+    #[arg(value_name = "FILE")]
+    pub files: Vec<PathBuf>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self::parse()
+    }
+}
+
+// run
+
+// Solves the selected parts of the day's puzzle for each input file (or stdin), printing each answer
+pub fn run<F1, F2>(day: u32, part1: F1, part2: F2) -> Result<(), Error>
+where
+    F1: Fn(&str) -> Result<Answer, Error>,
+    F2: Fn(&str) -> Result<Answer, Error>,
+{
+    run_with_args(day, &Args::from_env(), part1, part2)
+}
+
+// Like run, for days that extend Args with arguments of their own
+pub fn run_with_args<F1, F2>(day: u32, args: &Args, part1: F1, part2: F2) -> Result<(), Error>
+where
+    F1: Fn(&str) -> Result<Answer, Error>,
+    F2: Fn(&str) -> Result<Answer, Error>,
+{
+    args.log.init()?;
+    args.images.init()?;
+
+    if args.files.is_empty() {
+        let input = read_to_string(stdin())?;
+        return solve_input(day, args, None, &input, &part1, &part2);
+    }
+
+    let mut num_failed = 0;
+
+    for path in &args.files {
+        let label = path.display().to_string();
+
+        if args.format == Format::Text {
+            println!("{label}:");
+        }
+
+        // a bad file is reported, and the rest still get solved
+        let result = fs::read_to_string(path)
+            .map_err(Error::from)
+            .and_then(|input| solve_input(day, args, Some(&label), &input, &part1, &part2));
+
+        if let Err(err) = result {
+            eprintln!("{label}: error: {err:#}");
+            num_failed += 1;
+        }
+    }
+
+    ensure!(num_failed == 0, "{num_failed} input(s) failed");
+    Ok(())
+}
+
+fn solve_input<F1, F2>(
+    day: u32,
+    args: &Args,
+    label: Option<&str>,
+    input: &str,
+    part1: &F1,
+    part2: &F2,
+) -> Result<(), Error>
+where
+    F1: Fn(&str) -> Result<Answer, Error>,
+    F2: Fn(&str) -> Result<Answer, Error>,
+{
+    for part in args.part.parts() {
+        let start = Instant::now();
+        let (answer, details) = detail::record(|| match part {
+            Part::One => part1(input),
+            Part::Two => part2(input),
+        });
+        let elapsed = start.elapsed();
+
+        match args.format {
+            Format::Text => println!("{}", answer?),
+            Format::Json => {
+                let report = Report {
+                    input: label,
+                    ..Report::new(day, *part, &answer, elapsed, &details)
+                };
+                println!("{}", report.to_json());
+                answer?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anyhow::{anyhow, Error};
+use std::fmt;
+use std::fs::File;
+use std::io::{stderr, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+// How much diagnostic output the solvers write, on top of their answers
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Quiet,
+    Verbose, // a line or two per step, like which packets are in order
+    Trace,   // everything, like whole grids and evaluation trees
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+// None writes to stderr
+static SINK: Mutex<Option<File>> = Mutex::new(None);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+// Sends output to a file instead of stderr
+pub fn set_file(file: File) {
+    *SINK.lock().unwrap_or_else(|err| err.into_inner()) = Some(file);
+}
+
+// Writes one line of output; the verbose! and trace! macros check the level first
+pub fn write_line(args: fmt::Arguments) {
+    let mut sink = SINK.lock().unwrap_or_else(|err| err.into_inner());
+
+    // diagnostics are best effort, and not worth failing a solve over
+    let _ = match sink.as_mut() {
+        Some(file) => writeln!(file, "{args}"),
+        None => writeln!(stderr().lock(), "{args}"),
+    };
+}
+
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Verbose) {
+            $crate::log::write_line(format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write_line(format_args!($($arg)*));
+        }
+    };
+}
+
+// LogArgs
+
+// Command line arguments controlling diagnostic output, for flattening into other Args
+#[derive(Debug, clap::Args)]
+pub struct LogArgs {
+    /// Print a summary of what the solvers are doing
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// Print everything the solvers can tell about what they're doing
+    #[arg(long, global = true)]
+    pub trace: bool,
+
+    /// Write diagnostic output to a file instead of stderr
+    #[arg(long, global = true, value_name = "FILE")]
+    pub log_file: Option<PathBuf>,
+}
+
+impl LogArgs {
+    pub fn level(&self) -> Level {
+        if self.trace {
+            Level::Trace
+        } else if self.verbose {
+            Level::Verbose
+        } else {
+            Level::Quiet
+        }
+    }
+
+    pub fn init(&self) -> Result<(), Error> {
+        if let Some(path) = &self.log_file {
+            let file = File::create(path).map_err(|err| anyhow!("{}: {err}", path.display()))?;
+            set_file(file);
+        }
+
+        set_level(self.level());
+        Ok(())
+    }
+}
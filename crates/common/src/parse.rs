@@ -0,0 +1,101 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+use std::fmt;
+use std::str::FromStr;
+
+// Where and why a puzzle input failed to parse
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,   // 1-based
+    pub column: usize, // 1-based, in characters
+    pub text: String,  // what was found there, up to the end of its line
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            f.write_str("end of line")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    // An error at span, which must be a slice of input (like a line or a token from it)
+    //
+    // A span from anywhere else is reported at the end of the input.
+    pub fn at(input: &str, span: &str, expected: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + span.len() <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        let found = if offset == input.len() { "" } else { span };
+        let text = found.lines().next().unwrap_or("").to_owned();
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text,
+            expected: expected.into(),
+        }
+    }
+
+    // An error at the end of the input, for things that never showed up
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    // Converts an error from a nom parser run over input
+    //
+    // The innermost context() is used for what was expected, falling back on what nom was looking for.
+    pub fn from_nom(input: &str, err: nom::Err<VerboseError<&str>>) -> Self {
+        let err = match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => err,
+            nom::Err::Incomplete(_) => return Self::at_end(input, "more input"),
+        };
+
+        let Some((rest, kind)) = err.errors.first() else {
+            return Self::at_end(input, "valid input");
+        };
+
+        let expected = err
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            })
+            .unwrap_or_else(|| match kind {
+                VerboseErrorKind::Char(ch) => format!("{ch:?}"),
+                VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                VerboseErrorKind::Context(context) => context.to_string(),
+            });
+
+        // nom leaves the rest of the input; the offending text is the start of it
+        Self::at(input, rest, expected)
+    }
+}
+
+// Parses token (a slice of input) with FromStr, reporting failure at the token
+pub fn parse_token<T: FromStr>(
+    input: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
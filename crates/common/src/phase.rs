@@ -0,0 +1,98 @@
+use crate::alloc::{self, AllocStats};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Phase {
+    pub name: &'static str,
+    #[serde(serialize_with = "serialize_secs")]
+    pub elapsed: Duration,
+    // None when allocations aren't being counted, and peak_bytes is also None for the unmeasured rest of a solve
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<usize>,
+}
+
+fn serialize_secs<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(elapsed.as_secs_f64())
+}
+
+impl Phase {
+    fn new(name: &'static str, elapsed: Duration, allocs: AllocStats) -> Self {
+        Self {
+            name,
+            elapsed,
+            allocations: Some(allocs.allocations),
+            peak_bytes: Some(allocs.peak_bytes),
+        }
+    }
+}
+
+thread_local! {
+    static RECORDED: RefCell<Option<Vec<Phase>>> = const { RefCell::new(None) };
+}
+
+// Times one phase of a solver (like parsing) if the caller is recording
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let (result, allocs) = alloc::measure(f);
+    let elapsed = start.elapsed();
+
+    RECORDED.with(|recorded| {
+        if let Some(phases) = recorded.borrow_mut().as_mut() {
+            phases.push(Phase::new(name, elapsed, allocs));
+        }
+    });
+
+    result
+}
+
+// Runs f, returning its result along with the phases it went through
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Phase>) {
+    let outer = RECORDED.with(|recorded| recorded.replace(Some(Vec::new())));
+    let result = f();
+    let phases = RECORDED.with(|recorded| recorded.replace(outer));
+    (result, phases.unwrap_or_default())
+}
+
+// Runs a whole part, breaking it down into the phases it went through, the rest of the solve, and the total
+//
+// Phases that happen more than once are added together.
+pub fn profile<T>(f: impl FnOnce() -> T) -> (T, Vec<Phase>) {
+    let start = Instant::now();
+    let ((result, recorded), allocs) = alloc::measure(|| record(f));
+    let total = Phase::new("total", start.elapsed(), allocs);
+
+    let mut phases: Vec<Phase> = Vec::new();
+    for phase in recorded {
+        match phases.iter_mut().find(|p| p.name == phase.name) {
+            Some(p) => {
+                p.elapsed += phase.elapsed;
+                p.allocations = p.allocations.zip(phase.allocations).map(|(a, b)| a + b);
+                p.peak_bytes = p.peak_bytes.max(phase.peak_bytes);
+            }
+            None => phases.push(phase),
+        }
+    }
+
+    let in_phases = phases.iter().map(|p| p.elapsed).sum::<Duration>();
+    let allocs_in_phases = phases.iter().filter_map(|p| p.allocations).sum::<u64>();
+    phases.push(Phase {
+        name: "solve",
+        elapsed: total.elapsed.saturating_sub(in_phases),
+        allocations: Some(allocs.allocations.saturating_sub(allocs_in_phases)),
+        peak_bytes: None,
+    });
+    phases.push(total);
+
+    if !alloc::installed() {
+        for phase in &mut phases {
+            phase.allocations = None;
+            phase.peak_bytes = None;
+        }
+    }
+
+    (result, phases)
+}
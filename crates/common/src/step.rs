@@ -0,0 +1,47 @@
+use anyhow::{anyhow, Error};
+use std::fmt;
+use std::sync::mpsc::SyncSender;
+use std::sync::Mutex;
+
+// One step of a simulation: what just happened, and the board after it
+#[derive(Clone, Debug)]
+pub struct Step {
+    pub label: String,
+    pub board: String,
+}
+
+impl Step {
+    // The board is drawn with its Debug renderer
+    pub fn new(label: impl Into<String>, board: &impl fmt::Debug) -> Self {
+        Self {
+            label: label.into(),
+            board: format!("{board:?}"),
+        }
+    }
+}
+
+// None until something (like aoc step) wants to watch
+static SINK: Mutex<Option<SyncSender<Step>>> = Mutex::new(None);
+
+// Sends steps to sender, which holds up the solver until they're received
+pub fn set_sink(sender: Option<SyncSender<Step>>) {
+    *SINK.lock().unwrap_or_else(|err| err.into_inner()) = sender;
+}
+
+pub fn enabled() -> bool {
+    SINK.lock().unwrap_or_else(|err| err.into_inner()).is_some()
+}
+
+// Hands the next step to whoever is watching; f is only called if someone is
+//
+// Fails once the watcher has gone, so the solver stops rather than running on unseen.
+pub fn step(f: impl FnOnce() -> Step) -> Result<(), Error> {
+    let sender = SINK.lock().unwrap_or_else(|err| err.into_inner()).clone();
+    let Some(sender) = sender else {
+        return Ok(());
+    };
+
+    sender
+        .send(f())
+        .map_err(|_| anyhow!("Stopped stepping through the simulation"))
+}
@@ -0,0 +1,40 @@
+use anyhow::Error;
+use common::{parse_token, Answer, ParseError, Part};
+use itertools::Itertools as _;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input
+        .lines()
+        .group_by(|line| line.is_empty())
+        .into_iter()
+        .filter_map(|(is_empty, group)| if is_empty { None } else { Some(group) })
+        .map(|group| {
+            group
+                .map(|line| parse_token(input, line, "a number of calories"))
+                .collect()
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let elves = common::phase("parse", || parse(input))?;
+
+    let mut calories = elves
+        .iter()
+        .map(|items| items.iter().sum::<u32>())
+        .collect::<Vec<_>>();
+
+    calories.sort_unstable();
+
+    let topn = if part == Part::One { 1 } else { 3 };
+    let top_calories = calories.iter().rev().take(topn).sum::<u32>();
+    Ok(top_calories.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,150 @@
+use anyhow::Error;
+use common::{parse_token, Answer, ParseError, Part};
+use std::str::FromStr;
+
+fn split2<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let mut splitter = line.split_ascii_whitespace();
+    let a = splitter
+        .next()
+        .ok_or_else(|| ParseError::at(input, line, "a letter"))?;
+    let b = splitter
+        .next()
+        .ok_or_else(|| ParseError::at(input, &line[line.len()..], "a second letter"))?;
+    Ok((a, b))
+}
+
+// Shape
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn score(self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    fn wins_against(self) -> Shape {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+
+    fn loses_against(self) -> Shape {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(Error::msg("Invalid Shape")),
+        }
+    }
+}
+
+// Outcome
+
+#[derive(Clone, Copy)]
+enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn new(theirs: Shape, ours: Shape) -> Self {
+        if ours.wins_against() == theirs {
+            Self::Win
+        } else if ours.loses_against() == theirs {
+            Self::Loss
+        } else {
+            Self::Draw
+        }
+    }
+
+    fn score(self) -> u32 {
+        match self {
+            Self::Loss => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::Loss),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(Error::msg("Invalid Outcome")),
+        }
+    }
+}
+
+// The meaning of the second column depends on the part, so it's left as a token
+pub fn parse(input: &str) -> Result<Vec<(Shape, &str)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (token1, token2) = split2(input, line)?;
+            let theirs = parse_token(input, token1, "A, B or C")?;
+
+            if !matches!(token2, "X" | "Y" | "Z") {
+                return Err(ParseError::at(input, token2, "X, Y or Z"));
+            }
+
+            Ok((theirs, token2))
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let rounds = common::phase("parse", || parse(input))?;
+
+    let mut score = 0;
+    for (theirs, token2) in rounds {
+        let ours = if part == Part::One {
+            Shape::from_str(token2)?
+        } else {
+            match Outcome::from_str(token2)? {
+                Outcome::Loss => theirs.wins_against(),
+                Outcome::Draw => theirs,
+                Outcome::Win => theirs.loses_against(),
+            }
+        };
+
+        score += Outcome::new(theirs, ours).score() + ours.score();
+    }
+
+    Ok(score.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,105 @@
+use anyhow::{ensure, Error};
+use common::{Answer, ParseError, Part};
+use itertools::Itertools as _;
+
+fn prio(b: u8) -> u32 {
+    if b.is_ascii_lowercase() {
+        (b - b'a' + 1) as u32
+    } else if b.is_ascii_uppercase() {
+        (b - b'A' + 27) as u32
+    } else {
+        panic!("invalid byte");
+    }
+}
+
+fn make_bit_set(line: &str) -> u64 {
+    line.bytes().fold(0u64, |bits, b| bits | (1 << prio(b)))
+}
+
+fn sum_bits(x: u64) -> u32 {
+    let mut sum = 0;
+    let mut n = 0;
+
+    while (x >> n) > 0 {
+        let tz = (x >> n).trailing_zeros();
+        n += tz + 1;
+        sum += n - 1;
+    }
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some(idx) = line.find(|ch: char| !ch.is_ascii_alphabetic()) {
+                return Err(ParseError::at(input, &line[idx..], "an item (a-z or A-Z)"));
+            }
+
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(input, line, "an even number of items"));
+            }
+
+            Ok(line)
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let rucksacks = common::phase("parse", || parse(input))?;
+
+    if part == Part::One {
+        let prio_sum = rucksacks
+            .iter()
+            .map(|line| {
+                let half_len = line.len() / 2;
+                let left = make_bit_set(&line[..half_len]);
+                let right = make_bit_set(&line[half_len..]);
+                sum_bits(left & right)
+            })
+            .sum::<u32>();
+
+        Ok(prio_sum.into())
+    } else {
+        ensure!(
+            rucksacks.len().is_multiple_of(3),
+            "Expected elves in groups of three, but there are {}",
+            rucksacks.len()
+        );
+
+        let prio_sum = rucksacks
+            .iter()
+            .chunks(3)
+            .into_iter()
+            .map(|chunk| {
+                let badges = chunk
+                    .fold(None, |common_items: Option<u64>, line: &&str| {
+                        let items = make_bit_set(line);
+                        if let Some(common_items) = common_items {
+                            Some(common_items & items)
+                        } else {
+                            Some(items)
+                        }
+                    })
+                    .unwrap();
+
+                ensure!(
+                    badges != 0,
+                    "A group of elves has no item in common for a badge"
+                );
+                Ok(sum_bits(badges))
+            })
+            .sum::<Result<u32, Error>>()?;
+
+        Ok(prio_sum.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
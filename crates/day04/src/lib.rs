@@ -0,0 +1,60 @@
+use anyhow::Error;
+use common::{parse_token, Answer, ParseError, Part};
+use std::ops::RangeInclusive;
+
+// The section assignments of a pair of elves
+pub type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+fn totally_includes(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    b.start() >= a.start() && b.end() <= a.end()
+}
+
+fn overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    !(a.end() < b.start() || a.start() > b.end())
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let parse_range = |range: &str| -> Result<RangeInclusive<u32>, ParseError> {
+        let (low, high) = range
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input, range, "a range like 2-4"))?;
+        let low = parse_token(input, low, "a section number")?;
+        let high = parse_token(input, high, "a section number")?;
+        Ok(low..=high)
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (a, b) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, line, "two ranges separated by a comma"))?;
+            Ok((parse_range(a)?, parse_range(b)?))
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let pairs = common::phase("parse", || parse(input))?;
+
+    let count = pairs
+        .iter()
+        .filter(|(a, b)| {
+            if part == Part::One {
+                totally_includes(a, b) || totally_includes(b, a)
+            } else {
+                overlaps(a, b)
+            }
+        })
+        .count();
+
+    Ok(count.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,184 @@
+use anyhow::Error;
+use common::{parse_token, Answer, ParseError, Part, Step};
+use regex::Regex;
+use std::fmt;
+
+enum State {
+    Stacks,
+    Instructions,
+}
+
+pub struct Instruction {
+    pub count: usize,
+    pub stack_from: usize,
+    pub stack_to: usize,
+}
+
+// The starting stacks (bottom crate first) and the rearrangement procedure
+pub struct Plan {
+    pub stacks: Vec<String>,
+    pub instructions: Vec<Instruction>,
+}
+
+// Draws stacks the way the puzzle input does, tops up and numbers underneath
+struct Stacks<'a>(&'a [String]);
+
+impl fmt::Debug for Stacks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.0.iter().map(String::len).max().unwrap_or(0);
+
+        for row in (0..height).rev() {
+            let cells = self
+                .0
+                .iter()
+                .map(|stack| match stack.as_bytes().get(row) {
+                    Some(ch) => format!("[{}]", *ch as char),
+                    None => "   ".to_owned(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join(" ").trim_end())?;
+        }
+
+        let numbers = (1..=self.0.len())
+            .map(|n| format!(" {n} "))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", numbers.join(" ").trim_end())
+    }
+}
+
+fn rev_string(s: &str) -> String {
+    s.chars().rev().collect::<String>()
+}
+
+pub fn parse(input: &str) -> Result<Plan, ParseError> {
+    let crate_regex = Regex::new("\\[([A-Z])\\]").unwrap();
+    let move_regex = Regex::new("^move (\\d+) from (\\d+) to (\\d+)$").unwrap();
+    let mut state = State::Stacks;
+    let mut stacks: Vec<String> = Vec::new();
+    let mut instructions: Vec<Instruction> = Vec::new();
+
+    for line in input.lines() {
+        if line.is_empty() {
+            state = State::Instructions;
+            continue;
+        }
+
+        match state {
+            State::Stacks => {
+                // the row numbering the stacks says nothing the crates don't
+                let is_digits = |token: &str| token.bytes().all(|b| b.is_ascii_digit());
+                if line.split_whitespace().all(is_digits) {
+                    continue;
+                }
+
+                let mut i = 0;
+                while i * 4 < line.len() {
+                    let token_len = std::cmp::min(line.len() - i * 4, 3);
+                    let token = line
+                        .get(i * 4..i * 4 + token_len)
+                        .ok_or_else(|| ParseError::at(input, line, "a row of crates"))?
+                        .trim();
+
+                    if !token.is_empty() {
+                        let captures = crate_regex
+                            .captures(token)
+                            .ok_or_else(|| ParseError::at(input, line, "a crate like [A]"))?;
+
+                        if i >= stacks.len() {
+                            stacks.resize_with(i + 1, Default::default);
+                        }
+
+                        stacks[i].push_str(&captures[1]);
+                    }
+
+                    i += 1;
+                }
+            }
+            State::Instructions => {
+                let captures = move_regex.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "an instruction like \"move 1 from 2 to 3\"")
+                })?;
+
+                let stack_number = |idx: usize| -> Result<usize, ParseError> {
+                    let token = captures.get(idx).unwrap().as_str();
+                    let expected = format!("a stack number from 1 to {}", stacks.len());
+                    let number = parse_token(input, token, &expected)?;
+                    if (1..=stacks.len()).contains(&number) {
+                        Ok(number)
+                    } else {
+                        Err(ParseError::at(input, token, expected))
+                    }
+                };
+
+                let count = parse_token(input, captures.get(1).unwrap().as_str(), "a count")?;
+                let stack_from = stack_number(2)?;
+                let stack_to = stack_number(3)?;
+
+                instructions.push(Instruction {
+                    count,
+                    stack_from,
+                    stack_to,
+                });
+            }
+        }
+    }
+
+    for stack in &mut stacks {
+        *stack = rev_string(stack);
+    }
+
+    Ok(Plan {
+        stacks,
+        instructions,
+    })
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let Plan {
+        mut stacks,
+        instructions,
+    } = common::phase("parse", || parse(input))?;
+
+    for instr in &instructions {
+        let mut to_move = String::new();
+
+        for _i in 0..instr.count {
+            let c = stacks[instr.stack_from - 1]
+                .pop()
+                .ok_or_else(|| Error::msg("Moved a crate from an empty stack"))?;
+            to_move.push(c);
+        }
+
+        if part == Part::Two {
+            to_move = rev_string(&to_move);
+        }
+
+        stacks[instr.stack_to - 1].push_str(&to_move);
+
+        common::step(|| {
+            let label = format!(
+                "move {} from {} to {}",
+                instr.count, instr.stack_from, instr.stack_to
+            );
+            Step::new(label, &Stacks(&stacks))
+        })?;
+    }
+
+    let mut result = String::new();
+
+    for stack in &stacks {
+        if let Some(c) = stack.chars().last() {
+            result.push(c);
+        }
+    }
+
+    Ok(result.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
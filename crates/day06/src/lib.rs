@@ -0,0 +1,64 @@
+use anyhow::Error;
+use common::{Answer, ParseError, Part};
+
+fn letter_index(ch: u8) -> u8 {
+    match ch {
+        b'a'..=b'z' => ch - b'a',
+        b'A'..=b'Z' => ch - b'A' + 26,
+        _ => panic!("invalid letter"),
+    }
+}
+
+fn all_distinct(chars: &[u8]) -> bool {
+    let num_distinct = chars
+        .iter()
+        .fold(0u64, |acc, ch| acc | (1 << letter_index(*ch)))
+        .count_ones();
+    num_distinct as usize == chars.len()
+}
+
+// The example input has several datastreams, one per line
+pub fn parse(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some(idx) = line.find(|ch: char| !ch.is_ascii_alphabetic()) {
+                return Err(ParseError::at(input, &line[idx..], "a letter"));
+            }
+
+            Ok(line.as_bytes())
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let datastreams = common::phase("parse", || parse(input))?;
+    let marker_len = if part == Part::One { 4 } else { 14 };
+
+    let marker_starts = datastreams
+        .iter()
+        .map(|line| {
+            (marker_len..line.len())
+                .find(|&i| all_distinct(&line[i - marker_len..i]))
+                .ok_or_else(|| Error::msg("No marker in datastream"))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    match marker_starts[..] {
+        [marker_start] => Ok(marker_start.into()),
+        _ => Ok(marker_starts
+            .iter()
+            .map(|marker_start| marker_start.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into()),
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,129 @@
+use anyhow::Error;
+use common::{parse_token, Answer, ParseError, Part};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub struct Entry {
+    pub path: PathBuf,
+    pub size: u32,
+}
+
+// Replays the terminal session to find every file and its size
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let mut cwd = PathBuf::from("/");
+    let mut i = 0;
+    let mut entries = Vec::new();
+
+    while i < lines.len() {
+        common::trace!("{:?}", lines[i]);
+
+        if let Some(dir) = lines[i].strip_prefix("$ cd ") {
+            if dir.starts_with("/") {
+                cwd = PathBuf::from(dir);
+            } else if dir == ".." {
+                cwd.pop();
+            } else {
+                cwd.push(dir);
+            }
+
+            i += 1;
+        } else if lines[i] == "$ ls" {
+            i += 1;
+
+            while i < lines.len() && !lines[i].starts_with("$") {
+                let tokens = lines[i].split_ascii_whitespace().collect::<Vec<_>>();
+
+                match tokens[..] {
+                    ["dir", _] => {
+                        // ignore
+                    }
+                    [size, name] => {
+                        let size = parse_token(input, size, "a file size or \"dir\"")?;
+                        entries.push(Entry {
+                            path: cwd.join(name),
+                            size,
+                        });
+                    }
+                    _ => {
+                        return Err(ParseError::at(input, lines[i], "a size and a name"));
+                    }
+                }
+
+                i += 1;
+            }
+        } else {
+            return Err(ParseError::at(input, lines[i], "\"$ cd\" or \"$ ls\""));
+        }
+    }
+
+    Ok(entries)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let entries = common::phase("parse", || parse(input))?;
+
+    common::trace!("{entries:?}");
+
+    let dir_sizes = common::phase("preprocess", || {
+        // collect all directories
+        let mut dirs = HashSet::new();
+
+        for entry in &entries {
+            let mut path = entry.path.clone();
+            while path.pop() {
+                dirs.insert(path.clone());
+            }
+        }
+
+        common::trace!("{dirs:?}");
+
+        // calculate sizes of directories
+        let mut dir_sizes = Vec::new();
+
+        for dir in &dirs {
+            let size = entries
+                .iter()
+                .filter(|e| e.path.starts_with(dir))
+                .map(|e| e.size)
+                .sum::<u32>();
+
+            dir_sizes.push(size);
+        }
+
+        dir_sizes.sort_unstable();
+        dir_sizes
+    });
+
+    common::trace!("{dir_sizes:?}");
+
+    let result = if part == Part::One {
+        // calculate sum of directories with size <= 100000
+        dir_sizes
+            .into_iter()
+            .take_while(|size| *size <= 100000)
+            .sum::<u32>()
+    } else {
+        // calculate amount of space we need to free
+        let root_size = dir_sizes.last().ok_or_else(|| Error::msg("No files"))?; // the root will always be the largest directory
+        let min_to_free = root_size.saturating_sub(40_000_000);
+
+        // find the size of the smallest directory to remove that will recover the needed space
+        dir_sizes
+            .into_iter()
+            .find(|size| *size >= min_to_free)
+            .ok_or_else(|| Error::msg("No directory is big enough to free"))?
+    };
+
+    Ok(result.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,175 @@
+use anyhow::Error;
+use common::image;
+use common::{Answer, ParseError, Part};
+use grid::traversal::{self, Action};
+use grid::Grid;
+use std::{cmp, fmt};
+
+fn do_max_height<F, I>(arr: &Grid<u8>, f: F) -> Grid<u8>
+where
+    F: FnOnce(usize, usize) -> I,
+    I: Iterator<Item = Action>,
+{
+    let mut res = Grid::new(arr.width(), arr.height());
+    let mut max_height = 0;
+
+    for action in f(arr.width(), arr.height()) {
+        match action {
+            Action::Visit(x, y) => {
+                res[(x, y)] = max_height;
+                max_height = cmp::max(max_height, arr[(x, y)]);
+            }
+            Action::Reset => {
+                max_height = 0;
+            }
+        }
+    }
+
+    res
+}
+
+fn do_max_dist<F, I>(arr: &Grid<u8>, f: F) -> Grid<u8>
+where
+    F: FnOnce(usize, usize) -> I,
+    I: Iterator<Item = Action>,
+{
+    let mut res = Grid::new(arr.width(), arr.height());
+    let mut tree_dists = [0; 10];
+
+    for action in f(arr.width(), arr.height()) {
+        match action {
+            Action::Visit(x, y) => {
+                res[(x, y)] = tree_dists[(arr[(x, y)] - 1) as usize];
+
+                for h in 0..10 {
+                    if h < arr[(x, y)] {
+                        tree_dists[h as usize] = 1;
+                    } else {
+                        tree_dists[h as usize] += 1;
+                    }
+                }
+            }
+            Action::Reset => {
+                tree_dists.fill(0);
+            }
+        }
+    }
+
+    res
+}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, "a tree height (0-9)", |ch| match ch {
+        '0'..='9' => Some(ch as u8 - b'0' + 1), // 0 becomes 1 so we can have 0 as a true minimum
+        _ => None,
+    })
+}
+
+fn render(grid: &Grid<u8>) -> impl fmt::Display + '_ {
+    grid.render(|height, f| write!(f, "{height:02} "))
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let trees = common::phase("parse", || parse(input))?;
+    common::trace!("trees\n{}", render(&trees));
+
+    if part == Part::One {
+        let (from_left, from_right, from_top, from_bottom) = common::phase("preprocess", || {
+            (
+                do_max_height(&trees, traversal::rows),
+                do_max_height(&trees, traversal::rows_rev),
+                do_max_height(&trees, traversal::columns),
+                do_max_height(&trees, traversal::columns_rev),
+            )
+        });
+
+        common::trace!("from_left\n{}", render(&from_left));
+        common::trace!("from_right\n{}", render(&from_right));
+        common::trace!("from_top\n{}", render(&from_top));
+        common::trace!("from_bottom\n{}", render(&from_bottom));
+
+        let mut visible = Grid::new(trees.width(), trees.height());
+        let mut num_visible = 0;
+
+        for x in 0..trees.width() {
+            for y in 0..trees.height() {
+                let height = trees[(x, y)];
+                if from_left[(x, y)] < height
+                    || from_right[(x, y)] < height
+                    || from_top[(x, y)] < height
+                    || from_bottom[(x, y)] < height
+                {
+                    visible[(x, y)] = 1;
+                    num_visible += 1;
+                }
+            }
+        }
+
+        common::trace!("visible\n{}", render(&visible));
+        image::still("day08-trees", || {
+            trees.to_image(4, |height| image::heat(*height as usize, 10))
+        })?;
+        image::still("day08-visible", || {
+            visible.to_image(4, |visible| match visible {
+                0 => image::BLACK,
+                _ => [90, 200, 90],
+            })
+        })?;
+        Ok(num_visible.into())
+    } else {
+        let (from_left, from_right, from_top, from_bottom) = common::phase("preprocess", || {
+            (
+                do_max_dist(&trees, traversal::rows),
+                do_max_dist(&trees, traversal::rows_rev),
+                do_max_dist(&trees, traversal::columns),
+                do_max_dist(&trees, traversal::columns_rev),
+            )
+        });
+
+        common::trace!("from_left\n{}", render(&from_left));
+        common::trace!("from_right\n{}", render(&from_right));
+        common::trace!("from_top\n{}", render(&from_top));
+        common::trace!("from_bottom\n{}", render(&from_bottom));
+
+        let mut scores = Grid::new(trees.width(), trees.height());
+        let mut best_pos_score = None;
+
+        for x in 0..trees.width() {
+            for y in 0..trees.height() {
+                let score = from_left[(x, y)] as usize
+                    * from_right[(x, y)] as usize
+                    * from_top[(x, y)] as usize
+                    * from_bottom[(x, y)] as usize;
+                scores[(x, y)] = score;
+
+                if let Some((best_pos, best_score)) = &mut best_pos_score {
+                    if score > *best_score {
+                        *best_pos = (x, y);
+                        *best_score = score;
+                    }
+                } else {
+                    best_pos_score = Some(((x, y), score));
+                }
+            }
+        }
+
+        common::verbose!("best_pos_score = {best_pos_score:?}");
+        image::still("day08-scenic", || {
+            let max_score = best_pos_score.map_or(0, |(_, score)| score);
+            scores.to_image(4, |score| image::heat(*score, max_score))
+        })?;
+
+        let (best_pos, best_score) =
+            best_pos_score.ok_or_else(|| Error::msg("No trees in input"))?;
+        common::detail("best_position", best_pos);
+        Ok(best_score.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
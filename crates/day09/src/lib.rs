@@ -0,0 +1,128 @@
+use anyhow::{ensure, Error};
+use common::{parse_token, Answer, ParseError, Part, Step};
+use geom::{BoundingBox2, Direction, Point2};
+use std::collections::HashSet;
+use std::fmt;
+
+fn move_tail(head: Point2<i32>, tail: Point2<i32>) -> Point2<i32> {
+    if head.chebyshev(tail) < 2 {
+        return tail;
+    }
+
+    tail + (head - tail).signum()
+}
+
+// Draws the knots (H, then 1 through 9 or T) over the places the tail has visited (#), with s as the start
+struct Rope<'a> {
+    positions: &'a [Point2<i32>],
+    visited: &'a HashSet<Point2<i32>>,
+}
+
+impl fmt::Debug for Rope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let start = Point2::default();
+        let mut bounds = BoundingBox2::new(start);
+        for point in self.positions.iter().chain(self.visited) {
+            bounds.include(*point);
+        }
+
+        let last = self.positions.len() - 1;
+
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let point = Point2::new(x, y);
+                let ch = match self.positions.iter().position(|pos| *pos == point) {
+                    Some(0) => 'H',
+                    Some(i) if i == last => 'T',
+                    Some(i) => char::from_digit(i as u32, 10).unwrap_or('?'),
+                    None if point == start => 's',
+                    None if self.visited.contains(&point) => '#',
+                    None => '.',
+                };
+                write!(f, "{ch}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Each motion of the head, as a direction and a number of steps
+pub fn parse(input: &str) -> Result<Vec<(Direction, u32)>, ParseError> {
+    let mut motions = Vec::new();
+
+    for line in input.lines() {
+        let tokens = line.split_ascii_whitespace().collect::<Vec<_>>();
+
+        let (dir, count) = match tokens[..] {
+            [dir, count] => (dir, count),
+            _ => return Err(ParseError::at(input, line, "a direction and a count")),
+        };
+
+        let dir = match dir {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return Err(ParseError::at(input, dir, "L, R, U or D")),
+        };
+
+        let count = parse_token(input, count, "a count")?;
+        motions.push((dir, count));
+    }
+
+    Ok(motions)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let motions = common::phase("parse", || parse(input))?;
+
+    // however the head moves, it can't get further than this from the start
+    let total_steps = motions.iter().map(|(_, count)| *count as u64).sum::<u64>();
+    ensure!(
+        total_steps <= i32::MAX as u64,
+        "The head takes {total_steps} steps, more than can be kept track of"
+    );
+
+    // simulate positions
+    let num_positions = if part == Part::One { 2 } else { 10 };
+
+    let mut positions: Vec<Point2<i32>> = Vec::new();
+    positions.resize_with(num_positions, Default::default);
+
+    let mut visited = HashSet::new();
+    visited.insert(*positions.last().unwrap());
+
+    for &(dir, count) in &motions {
+        for step in 1..=count {
+            for i in 0..positions.len() {
+                positions[i] = match i {
+                    0 => positions[i] + dir.vector(),
+                    _ => move_tail(positions[i - 1], positions[i]),
+                };
+            }
+
+            visited.insert(*positions.last().unwrap());
+
+            common::step(|| {
+                let rope = Rope {
+                    positions: &positions,
+                    visited: &visited,
+                };
+                Step::new(format!("{dir:?} {step} of {count}"), &rope)
+            })?;
+        }
+    }
+
+    Ok(visited.len().into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Error};
+use common::image::{self, Image};
+use common::{parse_token, Answer, ParseError, Part};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instr {
+    Noop,
+    Addx(i32),
+}
+
+impl Instr {
+    fn cycles(self) -> u32 {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+
+    // x is an i64 so adding up every addx, however large, can't overflow
+    fn execute(self, x: &mut i64) {
+        match self {
+            Self::Noop => (),
+            Self::Addx(value) => {
+                *x += i64::from(value);
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instr>, ParseError> {
+    let mut instrs = Vec::new();
+
+    for line in input.lines() {
+        let instr = if line == "noop" {
+            Instr::Noop
+        } else if let Some(value) = line.strip_prefix("addx ") {
+            Instr::Addx(parse_token(input, value, "a number")?)
+        } else {
+            return Err(ParseError::at(input, line, "noop or addx"));
+        };
+
+        instrs.push(instr);
+    }
+
+    Ok(instrs)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let instrs = common::phase("parse", || parse(input))?;
+
+    let mut iter = instrs.iter().peekable();
+    let mut instr_age = 0;
+
+    let mut cycle = 0i64;
+    let mut x = 1;
+    let mut result = 0i64;
+    let mut screen = Vec::new();
+    screen.resize(40 * 6, false);
+
+    while let Some(instr) = iter.peek() {
+        if (cycle - 19) % 40 == 0 {
+            result = (cycle + 1)
+                .checked_mul(x)
+                .and_then(|strength| result.checked_add(strength))
+                .ok_or_else(|| {
+                    anyhow!("Signal strengths add up to too much at cycle {}", cycle + 1)
+                })?;
+        }
+
+        // programs can run past the last pixel, which nothing is drawn for
+        if i64::abs_diff(x, cycle % 40) <= 1 {
+            if let Some(pixel) = screen.get_mut(cycle as usize) {
+                *pixel = true;
+            }
+        }
+
+        instr_age += 1;
+
+        if instr_age == instr.cycles() {
+            instr.execute(&mut x);
+            iter.next();
+            instr_age = 0;
+        }
+
+        cycle += 1;
+    }
+
+    image::still("day10-crt", || {
+        const SCALE: usize = 8;
+        let mut image = Image::new(40 * SCALE, 6 * SCALE, image::BLACK);
+
+        for (idx, lit) in screen.iter().enumerate() {
+            if *lit {
+                let (x, y) = (idx % 40, idx / 40);
+                image.fill_rect(x * SCALE, y * SCALE, SCALE, SCALE, [120, 255, 120]);
+            }
+        }
+
+        image
+    })?;
+
+    if part == Part::One {
+        Ok(result.into())
+    } else {
+        let mut s = String::new();
+
+        for y in 0..6 {
+            if y > 0 {
+                s.push('\n');
+            }
+
+            for x in 0..40 {
+                let ch = if screen[y * 40 + x] { '#' } else { '.' };
+                s.push(ch);
+            }
+        }
+
+        Ok(s.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
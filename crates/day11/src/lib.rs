@@ -0,0 +1,312 @@
+use anyhow::{anyhow, ensure, Error};
+use common::{Answer, ParseError, Part};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, u64};
+use nom::combinator::{cut, eof, verify};
+use nom::error::{context, VerboseError};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, terminated};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+#[derive(Debug)]
+pub enum Operand {
+    Old,
+    Const(u64),
+}
+
+impl Operand {
+    fn resolve(&self, old: u64) -> u128 {
+        match self {
+            Self::Old => old.into(),
+            Self::Const(value) => (*value).into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Operator {
+    Add,
+    Mul,
+}
+
+impl Operator {
+    // u128 holds the sum or product of any two u64s
+    fn execute(&self, left: u128, right: u128) -> u128 {
+        match self {
+            Self::Add => left + right,
+            Self::Mul => left * right,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Expr {
+    pub left: Operand,
+    pub right: Operand,
+    pub oper: Operator,
+}
+
+impl Expr {
+    fn eval(&self, old: u64) -> u128 {
+        let left = self.left.resolve(old);
+        let right = self.right.resolve(old);
+        self.oper.execute(left, right)
+    }
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    pub initial_items: Vec<u64>,
+    pub oper: Expr,
+    pub test_divisor: u64,
+    pub true_monkey: u64,
+    pub false_monkey: u64,
+}
+
+#[cfg(not(feature = "parallel"))]
+#[derive(Debug)]
+struct MonkeyState {
+    items: Vec<u64>,
+    inspect_count: u64,
+}
+
+fn operand_var(input: &str) -> IResult<&str, Operand> {
+    let (input, _) = tag("old")(input)?;
+    Ok((input, Operand::Old))
+}
+
+fn operand_const(input: &str) -> IResult<&str, Operand> {
+    let (input, value) = u64(input)?;
+    Ok((input, Operand::Const(value)))
+}
+
+fn operand(input: &str) -> IResult<&str, Operand> {
+    context("old or a number", alt((operand_var, operand_const)))(input)
+}
+
+fn operator_add(input: &str) -> IResult<&str, Operator> {
+    let (input, _) = tag(" + ")(input)?;
+    Ok((input, Operator::Add))
+}
+
+fn operator_mul(input: &str) -> IResult<&str, Operator> {
+    let (input, _) = tag(" * ")(input)?;
+    Ok((input, Operator::Mul))
+}
+
+fn operator(input: &str) -> IResult<&str, Operator> {
+    context("\" + \" or \" * \"", alt((operator_add, operator_mul)))(input)
+}
+
+fn expr(input: &str) -> IResult<&str, Expr> {
+    let (input, left) = operand(input)?;
+    let (input, oper) = operator(input)?;
+    let (input, right) = operand(input)?;
+    Ok((input, Expr { left, oper, right }))
+}
+
+fn monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = context("\"Monkey \"", tag("Monkey "))(input)?;
+    // anything that starts like a monkey has to be one, so errors are reported where they happen
+    cut(monkey_body)(input)
+}
+
+fn monkey_body(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = context("a monkey number", u64)(input)?;
+    let (input, _) = context("\"Starting items\"", tag(":\n  Starting items: "))(input)?;
+    let (input, initial_items) =
+        context("a list of worry levels", separated_list1(tag(", "), u64))(input)?;
+    let (input, _) = context("\"Operation\"", tag("\n  Operation: new = "))(input)?;
+    let (input, oper) = expr(input)?;
+    let (input, _) = context("\"Test\"", tag("\n  Test: divisible by "))(input)?;
+    let (input, test_divisor) =
+        context("a non-zero divisor", verify(u64, |divisor| *divisor != 0))(input)?;
+    let (input, _) = context("\"If true\"", tag("\n    If true: throw to monkey "))(input)?;
+    let (input, true_monkey) = context("a monkey number", u64)(input)?;
+    let (input, _) = context("\"If false\"", tag("\n    If false: throw to monkey "))(input)?;
+    let (input, false_monkey) = context("a monkey number", u64)(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            initial_items,
+            oper,
+            test_divisor,
+            true_monkey,
+            false_monkey,
+        },
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let end = context("end of input", pair(multispace0, eof));
+    match terminated(separated_list1(tag("\n\n"), monkey), end)(input) {
+        Ok((_input, monkeys)) => Ok(monkeys),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
+}
+
+// The worry level of an item once monkey has inspected it, and the monkey it's thrown to
+fn inspect(monkey: &Monkey, item: u64, part: Part, modulo: u64) -> Result<(u64, usize), Error> {
+    // Update worry
+    let worry = monkey.oper.eval(item);
+
+    let item = match part {
+        // Drop worry level, which has to be known exactly to be divided
+        Part::One => u64::try_from(worry / 3)
+            .map_err(|_| anyhow!("Worry level {} is too large to keep track of", worry / 3))?,
+        // Only whether it divides by each monkey's divisor matters, which stays the same modulo their product
+        Part::Two => (worry % u128::from(modulo)) as u64,
+    };
+
+    // Pass item to next monkey
+    let target_monkey = if item.is_multiple_of(monkey.test_divisor) {
+        monkey.true_monkey
+    } else {
+        monkey.false_monkey
+    };
+
+    Ok((item, target_monkey as usize))
+}
+
+// How many times each monkey inspects an item
+#[cfg(not(feature = "parallel"))]
+fn inspect_counts(monkeys: &[Monkey], part: Part, modulo: u64) -> Result<Vec<u64>, Error> {
+    let mut states = monkeys
+        .iter()
+        .map(|monkey| MonkeyState {
+            items: monkey.initial_items.clone(),
+            inspect_count: 0,
+        })
+        .collect::<Vec<_>>();
+
+    let nrounds = if part == Part::One { 20 } else { 10000 };
+
+    for _round in 0..nrounds {
+        for (monkey_num, monkey) in monkeys.iter().enumerate() {
+            let items = std::mem::take(&mut states[monkey_num].items);
+
+            for item in items.into_iter() {
+                let (item, target_monkey) = inspect(monkey, item, part, modulo)?;
+
+                // Update total inspection count
+                states[monkey_num].inspect_count += 1;
+
+                states[target_monkey].items.push(item);
+            }
+        }
+    }
+
+    common::trace!("{:?}", states);
+
+    Ok(states.iter().map(|state| state.inspect_count).collect())
+}
+
+// Items never affect each other, so each can be followed on its own through every round, in parallel. One thrown to a
+// later monkey is inspected again in the same round, and one thrown to the same or an earlier monkey waits for the next
+// round.
+#[cfg(feature = "parallel")]
+fn inspect_counts(monkeys: &[Monkey], part: Part, modulo: u64) -> Result<Vec<u64>, Error> {
+    let items = monkeys
+        .iter()
+        .enumerate()
+        .flat_map(|(monkey_num, monkey)| {
+            monkey
+                .initial_items
+                .iter()
+                .map(move |item| (monkey_num, *item))
+        })
+        .collect::<Vec<_>>();
+
+    let item_counts = items
+        .par_iter()
+        .map(|&(monkey_num, item)| follow_item(monkeys, monkey_num, item, part, modulo))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut counts = vec![0; monkeys.len()];
+    for item_count in &item_counts {
+        for (count, item_count) in counts.iter_mut().zip(item_count) {
+            *count += item_count;
+        }
+    }
+
+    Ok(counts)
+}
+
+// How many times each monkey inspects an item starting with monkey_num
+#[cfg(feature = "parallel")]
+fn follow_item(
+    monkeys: &[Monkey],
+    mut monkey_num: usize,
+    mut item: u64,
+    part: Part,
+    modulo: u64,
+) -> Result<Vec<u64>, Error> {
+    let nrounds = if part == Part::One { 20 } else { 10000 };
+    let mut counts = vec![0; monkeys.len()];
+    let mut round = 0;
+
+    while round < nrounds {
+        let target_monkey;
+        (item, target_monkey) = inspect(&monkeys[monkey_num], item, part, modulo)?;
+
+        // Update total inspection count
+        counts[monkey_num] += 1;
+
+        if target_monkey <= monkey_num {
+            round += 1;
+        }
+
+        monkey_num = target_monkey;
+    }
+
+    Ok(counts)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let monkeys = common::phase("parse", || parse(input))?;
+
+    for monkey in &monkeys {
+        for target_monkey in [monkey.true_monkey, monkey.false_monkey] {
+            ensure!(
+                (target_monkey as usize) < monkeys.len(),
+                "Monkey {target_monkey} does not exist"
+            );
+        }
+    }
+
+    let modulo = monkeys
+        .iter()
+        .try_fold(1u64, |acc, monkey| acc.checked_mul(monkey.test_divisor))
+        .ok_or_else(|| {
+            anyhow!("The monkeys' divisors multiply to more than can be kept track of")
+        })?;
+    common::verbose!("modulo: {modulo}");
+    common::detail("modulo", modulo);
+
+    let mut counts = inspect_counts(&monkeys, part, modulo)?;
+
+    common::trace!("{:?}", monkeys);
+    common::trace!("inspect counts: {:?}", counts);
+
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    let monkey_business = counts
+        .iter()
+        .take(2)
+        .try_fold(1u64, |acc, count| acc.checked_mul(*count))
+        .ok_or_else(|| anyhow!("Monkey business is too large to keep track of"))?;
+    Ok(monkey_business.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,163 @@
+use anyhow::Error;
+use common::image;
+use common::{Answer, ParseError, Part};
+use grid::Grid;
+use std::fmt;
+
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start_pos: (usize, usize),
+    end_pos: (usize, usize),
+}
+
+impl fmt::Debug for HeightMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.heights.render(|h, f| write!(f, "{h:02} ")))?;
+        writeln!(f, "start_pos: {:?}", self.start_pos)?;
+        writeln!(f, "end_pos: {:?}", self.end_pos)?;
+        Ok(())
+    }
+}
+
+impl HeightMap {
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    pub fn start_pos(&self) -> (usize, usize) {
+        self.start_pos
+    }
+
+    pub fn end_pos(&self) -> (usize, usize) {
+        self.end_pos
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Breadcrumb {
+    dist: u32,
+}
+
+struct WorkItem {
+    position: (usize, usize), // current position
+    dist: u32,                // distance from the end (the end itself has dist 0)
+}
+
+pub fn parse(input: &str) -> Result<HeightMap, ParseError> {
+    let chars = Grid::parse(input, "a height (a-z), S or E", |ch| match ch {
+        'a'..='z' | 'S' | 'E' => Some(ch),
+        _ => None,
+    })?;
+
+    let start_pos = chars
+        .position(|ch| *ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a start position (S)"))?;
+    let end_pos = chars
+        .position(|ch| *ch == 'E')
+        .ok_or_else(|| ParseError::at_end(input, "an end position (E)"))?;
+
+    let heights = chars.map(|ch| match ch {
+        'S' => 0,
+        'E' => 26,
+        _ => *ch as u8 - b'a',
+    });
+
+    Ok(HeightMap {
+        heights,
+        start_pos,
+        end_pos,
+    })
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let map = common::phase("parse", || parse(input))?;
+    common::trace!("heights\n{map:?}");
+    let heights = map.heights();
+
+    let mut crumbs: Grid<Option<Breadcrumb>> = Grid::new(heights.width(), heights.height());
+
+    let mut work_queue = vec![WorkItem {
+        position: map.end_pos(),
+        dist: 0,
+    }];
+
+    while let Some(work) = work_queue.pop() {
+        if let Some(crumb) = crumbs[work.position] {
+            if crumb.dist <= work.dist {
+                // existing crumb got here quicker
+                continue;
+            }
+
+            // this crumb got here quicker
+        } else {
+            // no one has been here before!
+        }
+
+        // update crumb
+        crumbs[work.position] = Some(Breadcrumb { dist: work.dist });
+
+        // expand search in all directions
+        let (x, y) = work.position;
+        for position in heights.neighbors4(x, y) {
+            let next_height = heights[work.position];
+            let prev_height = heights[position];
+            if next_height > prev_height + 1 {
+                continue;
+            }
+
+            work_queue.push(WorkItem {
+                position,
+                dist: work.dist + 1,
+            });
+        }
+    }
+
+    common::trace!("{crumbs:?}");
+    image::still("day12-distances", || {
+        let max_dist = crumbs
+            .iter()
+            .filter_map(|(_, crumb)| crumb.map(|c| c.dist))
+            .max();
+        crumbs.to_image(4, |crumb| match crumb {
+            Some(crumb) => image::heat(crumb.dist as usize, max_dist.unwrap_or(0) as usize),
+            None => [40, 60, 120], // can't reach the end from here
+        })
+    })?;
+
+    if part == Part::One {
+        let start_crumb =
+            crumbs[map.start_pos()].ok_or_else(|| Error::msg("No path from start to end"))?;
+        return Ok(start_crumb.dist.into());
+    }
+
+    let mut best_dist = None;
+
+    for (position, crumb) in crumbs.iter() {
+        let Some(crumb) = crumb else {
+            continue;
+        };
+
+        if heights[position] != 0 {
+            continue;
+        }
+
+        if let Some(d) = best_dist {
+            if crumb.dist < d {
+                best_dist = Some(crumb.dist);
+            }
+        } else {
+            best_dist = Some(crumb.dist);
+        }
+    }
+
+    let best_dist = best_dist.ok_or_else(|| Error::msg("No hiking trail to end"))?;
+    Ok(best_dist.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
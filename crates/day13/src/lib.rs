@@ -0,0 +1,153 @@
+use anyhow::Error;
+use common::{Answer, ParseError, Part};
+use itertools::{EitherOrBoth, Itertools as _};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, i32, multispace0};
+use nom::combinator::{cut, eof};
+use nom::error::{context, ContextError, ErrorKind, ParseError as _, VerboseError};
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::{delimited, preceded};
+use std::cmp::Ordering;
+
+type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+// Deeper lists are rejected rather than risking the stack on hostile input
+const MAX_DEPTH: usize = 256;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Integer(i32),
+    List(Vec<Value>),
+}
+
+fn value_integer(input: &str) -> IResult<&str, Value> {
+    let (input, i) = i32(input)?;
+    Ok((input, Value::Integer(i)))
+}
+
+fn value_list(input: &str, depth: usize) -> IResult<&str, Value> {
+    if depth >= MAX_DEPTH {
+        let err = VerboseError::from_error_kind(input, ErrorKind::TooLarge);
+        let err = VerboseError::add_context(input, "lists nested at most 256 deep", err);
+        return Err(nom::Err::Failure(err));
+    }
+
+    let items = separated_list0(char(','), |input| value(input, depth + 1));
+    let close = context("',' or ']'", char(']'));
+    let (input, lst) = delimited(char('['), cut(items), cut(close))(input)?;
+    Ok((input, Value::List(lst)))
+}
+
+fn value(input: &str, depth: usize) -> IResult<&str, Value> {
+    let list = |input| value_list(input, depth);
+    context("an integer or a list", alt((value_integer, list)))(input)
+}
+
+fn top_value(input: &str) -> IResult<&str, Value> {
+    value(input, 0)
+}
+
+fn value_pair(input: &str) -> IResult<&str, (Value, Value)> {
+    let (input, value1) = top_value(input)?;
+    let (input, value2) = cut(preceded(char('\n'), top_value))(input)?;
+    Ok((input, (value1, value2)))
+}
+
+fn value_pairs(input: &str) -> IResult<&str, Vec<(Value, Value)>> {
+    let (input, pairs) = separated_list1(tag("\n\n"), value_pair)(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = context("a blank line between pairs", eof)(input)?;
+    Ok((input, pairs))
+}
+
+// Less if a pair of packets is in the right order, Greater if not, and Equal if they can't be told apart
+pub fn ordered(left: &Value, right: &Value) -> Ordering {
+    // println!("Compare {left:?} vs {right:?}");
+    match (left, right) {
+        (Value::Integer(left), Value::Integer(right)) => Ord::cmp(left, right),
+        (left @ Value::Integer(_), right) => {
+            let left = Value::List(vec![left.clone()]);
+            ordered(&left, right)
+        }
+        (left, right @ Value::Integer(_)) => {
+            let right = Value::List(vec![right.clone()]);
+            ordered(left, &right)
+        }
+        (Value::List(left), Value::List(right)) => {
+            for pair in left.iter().zip_longest(right.iter()) {
+                match pair {
+                    // If both values are lists, compare the first value of each list, then the second value, and so on.
+                    EitherOrBoth::Both(left, right) => {
+                        let ord = ordered(left, right);
+                        if ord != Ordering::Equal {
+                            return ord;
+                        }
+                    }
+                    // If the right list runs out of items first, the inputs are not in the right order.
+                    EitherOrBoth::Left(_) => return Ordering::Greater,
+                    // If the left list runs out of items first, the inputs are in the right order.
+                    EitherOrBoth::Right(_) => return Ordering::Less,
+                }
+            }
+
+            // If the lists are the same length and no comparison makes a decision about the order, continue checking the next part of the input.
+            Ordering::Equal
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Value, Value)>, ParseError> {
+    match value_pairs(input) {
+        Ok((_, pairs)) => Ok(pairs),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut pairs = common::phase("parse", || parse(input))?;
+
+    if part == Part::One {
+        // println!("{pairs:#?}");
+
+        let mut sum = 0;
+
+        for (i, (left, right)) in pairs.iter_mut().enumerate() {
+            if ordered(left, right).is_le() {
+                common::verbose!("{i} is ordered");
+                sum += i + 1;
+            } else {
+                common::verbose!("{i} is not ordered");
+            }
+        }
+
+        Ok(sum.into())
+    } else {
+        let mut values = pairs
+            .into_iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect::<Vec<_>>();
+
+        let divider1 = Value::List(vec![Value::List(vec![Value::Integer(2)])]);
+        let divider2 = Value::List(vec![Value::List(vec![Value::Integer(6)])]);
+
+        values.push(divider1.clone());
+        values.push(divider2.clone());
+        values.sort_by(ordered);
+
+        let index1 = values.binary_search_by(|v| ordered(v, &divider1)).unwrap();
+        let index2 = values.binary_search_by(|v| ordered(v, &divider2)).unwrap();
+        let decoder_key = (index1 + 1) * (index2 + 1);
+        common::detail("divider_indices", (index1 + 1, index2 + 1));
+
+        Ok(decoder_key.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
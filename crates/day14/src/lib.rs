@@ -0,0 +1,285 @@
+use anyhow::Error;
+use common::image::{self, Image, Rgb};
+use common::{Answer, ParseError, Part, Step};
+use geom::{BoundingBox2, Point2, Vector2};
+use grid::Grid;
+use nom::bytes::complete::tag;
+use nom::character::complete::multispace0;
+use nom::character::complete::{char, i32, newline};
+use nom::combinator::eof;
+use nom::error::{context, ContextError, ErrorKind, ParseError as _, VerboseError};
+use nom::multi::separated_list1;
+use std::fmt;
+
+pub type Point = Point2<i32>;
+type Vector = Vector2<i32>;
+
+const SAND_DROP: Point = Point::new(500, 0);
+
+type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+fn point(mut input: &str) -> IResult<&str, Point> {
+    let (x, y);
+    (input, x) = context("an x coordinate", i32)(input)?;
+    (input, _) = context("','", char(','))(input)?;
+    (input, y) = context("a y coordinate", i32)(input)?;
+    Ok((input, Point { x, y }))
+}
+
+fn points(input: &str) -> IResult<&str, Vec<Point>> {
+    let (rest, points) = separated_list1(tag(" -> "), point)(input)?;
+
+    // a diagonal is fatal, rather than letting the caller backtrack and complain about something else
+    let is_straight = points
+        .windows(2)
+        .all(|pair| pair[0].x == pair[1].x || pair[0].y == pair[1].y);
+    if !is_straight {
+        let err = VerboseError::from_error_kind(input, ErrorKind::Verify);
+        let err = VerboseError::add_context(input, "horizontal and vertical lines", err);
+        return Err(nom::Err::Failure(err));
+    }
+
+    Ok((rest, points))
+}
+
+fn rock_paths(mut input: &str) -> IResult<&str, Vec<Vec<Point>>> {
+    let lines;
+    (input, lines) = separated_list1(newline, points)(input)?;
+    (input, _) = multispace0(input)?;
+    (input, _) = context("\" -> \" or the next path", eof)(input)?;
+    Ok((input, lines))
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Cell {
+    fn char(self) -> char {
+        match self {
+            Self::Air => '.',
+            Self::Rock => '#',
+            Self::Sand => 'o',
+        }
+    }
+
+    fn color(self) -> Rgb {
+        match self {
+            Self::Air => image::BLACK,
+            Self::Rock => [128, 128, 128],
+            Self::Sand => [230, 190, 90],
+        }
+    }
+
+    fn is_air(self) -> bool {
+        matches!(self, Self::Air)
+    }
+}
+
+// The grid only covers the area within bounds, and everything outside it is air
+struct Cave {
+    cells: Grid<Cell>,
+    bounds: BoundingBox2<i32>,
+}
+
+impl fmt::Debug for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let render = self.cells.render(|cell, f| write!(f, "{}", cell.char()));
+        write!(f, "{render}")
+    }
+}
+
+impl Cave {
+    fn new(bounds: BoundingBox2<i32>) -> Self {
+        let size = bounds.max - bounds.min;
+        let cells = Grid::filled((size.x + 1) as usize, (size.y + 1) as usize, Cell::Air);
+        Self { cells, bounds }
+    }
+
+    fn grid_pos(&self, point: Point) -> Option<(usize, usize)> {
+        let delta = point - self.bounds.min;
+        Some((
+            usize::try_from(delta.x).ok()?,
+            usize::try_from(delta.y).ok()?,
+        ))
+    }
+
+    fn set(&mut self, point: Point, cell: Cell) {
+        let set = match self.grid_pos(point) {
+            Some((x, y)) => self.cells.set(x, y, cell),
+            None => false,
+        };
+
+        if !set {
+            common::verbose!("Out of range set");
+        }
+    }
+
+    fn get(&self, point: Point) -> Cell {
+        self.grid_pos(point)
+            .and_then(|(x, y)| self.cells.get(x, y).copied())
+            .unwrap_or(Cell::Air)
+    }
+
+    fn to_image(&self) -> Image {
+        self.cells.to_image(2, |cell| cell.color())
+    }
+}
+
+fn render_rock(cave: &mut Cave, mut a: Point, mut b: Point) {
+    if a.x == b.x {
+        let x = a.x;
+
+        if a.y > b.y {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        for y in a.y..=b.y {
+            cave.set(Point { x, y }, Cell::Rock);
+        }
+    } else if a.y == b.y {
+        let y = a.y;
+
+        if a.x > b.x {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        for x in a.x..=b.x {
+            cave.set(Point { x, y }, Cell::Rock);
+        }
+    } else {
+        unreachable!("Diagonal lines are rejected by the parser");
+    }
+}
+
+enum DropSandResult {
+    Blocked,
+    AtRest,
+    IntoAbyss,
+}
+
+fn drop_sand(cave: &mut Cave) -> DropSandResult {
+    let mut sand = SAND_DROP;
+
+    if !cave.get(sand).is_air() {
+        return DropSandResult::Blocked;
+    }
+
+    loop {
+        let deltas = [Vector::new(0, 1), Vector::new(-1, 1), Vector::new(1, 1)];
+
+        let mut moved = false;
+
+        for delta in &deltas {
+            let new_sand = sand + *delta;
+            if cave.get(new_sand).is_air() {
+                sand = new_sand;
+                moved = true;
+                break;
+            }
+        }
+
+        if moved {
+            if sand.y > cave.bounds.max.y {
+                return DropSandResult::IntoAbyss;
+            }
+        } else {
+            cave.set(sand, Cell::Sand);
+            return DropSandResult::AtRest;
+        }
+    }
+}
+
+// Covers every rock and the point the sand drops from
+fn calc_bounds(lines: &[Vec<Point>]) -> BoundingBox2<i32> {
+    let mut bounds = BoundingBox2::new(SAND_DROP);
+
+    for point in lines.iter().flatten() {
+        bounds.include(*point);
+    }
+
+    bounds
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    let (_, lines) = rock_paths(input).map_err(|err| ParseError::from_nom(input, err))?;
+    Ok(lines)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    // println!("input: {input:?}");
+
+    let mut lines = common::phase("parse", || parse(input))?;
+    // println!("lines: {lines:?}");
+
+    let mut bounds = calc_bounds(&lines);
+
+    if part == Part::Two {
+        let floor_begin = Point {
+            x: bounds.min.x - 1000,
+            y: bounds.max.y + 2,
+        };
+
+        let floor_end = Point {
+            x: bounds.max.x + 1000,
+            y: bounds.max.y + 2,
+        };
+
+        lines.push(vec![floor_begin, floor_end]);
+        bounds = calc_bounds(&lines);
+    }
+
+    // println!("bounds = {bounds:?}");
+
+    let mut cave = common::phase("preprocess", || {
+        let mut cave = Cave::new(bounds);
+
+        for line in &lines {
+            for [begin, end] in line.array_windows() {
+                render_rock(&mut cave, *begin, *end);
+            }
+        }
+
+        cave
+    });
+
+    // println!("{cave:?}");
+
+    let name = format!("day14-part{part}-cave");
+    let mut num_sand = 0;
+    loop {
+        match drop_sand(&mut cave) {
+            DropSandResult::Blocked => {
+                break;
+            }
+            DropSandResult::AtRest => {
+                image::frame(&name, || cave.to_image())?;
+                common::step(|| Step::new(format!("grain {}", num_sand + 1), &cave))?;
+            }
+            DropSandResult::IntoAbyss => {
+                if part == Part::Two {
+                    common::verbose!("Our infinite floor is not enough");
+                }
+
+                break;
+            }
+        }
+
+        num_sand += 1;
+    }
+
+    // println!("{cave:?}");
+    image::still(&name, || cave.to_image())?;
+    Ok(num_sand.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,309 @@
+use anyhow::{anyhow, ensure, Error};
+use common::{parse_token, Answer, ParseError, Part};
+use geom::Point2;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::ops::Range;
+
+// the row and search area from the puzzle text, for the real input and for the example
+pub const DISTRESS_Y: i32 = 2000000;
+pub const MAX_VAL: i32 = 4000000;
+pub const EXAMPLE_DISTRESS_Y: i32 = 10;
+pub const EXAMPLE_MAX_VAL: i32 = 20;
+
+pub type Point = Point2<i32>;
+
+#[derive(Debug)]
+pub struct Sensor {
+    pub sensor: Point,
+    pub beacon: Point,
+}
+
+// sorted, non-overlapping ranges of x coordinates
+#[derive(Debug, Default)]
+pub struct Exclusions {
+    ranges: Vec<Range<i32>>,
+}
+
+impl Exclusions {
+    pub fn ranges(&self) -> &[Range<i32>] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, x: i32) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= x);
+        self.ranges.get(idx).is_some_and(|range| range.contains(&x))
+    }
+
+    pub fn insert(&mut self, exclusion: Range<i32>) {
+        let idx = match self
+            .ranges
+            .binary_search_by_key(&exclusion.start, |range| range.start)
+        {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+
+        self.ranges.insert(idx, exclusion);
+
+        if idx > 0 {
+            self.try_merge_ranges(idx - 1);
+        }
+
+        self.try_merge_ranges(idx);
+    }
+
+    fn try_merge_ranges(&mut self, idx: usize) {
+        while let Some(next_range) = self.ranges.get(idx + 1).cloned() {
+            let Some(this_range) = self.ranges.get_mut(idx) else {
+                break;
+            };
+
+            if this_range.end < next_range.start {
+                break;
+            }
+
+            this_range.end = std::cmp::max(this_range.end, next_range.end);
+            self.ranges.remove(idx + 1);
+        }
+    }
+}
+
+// Errors if a sensor rules out x coordinates too far out to fit in an i32
+pub fn calc_exclusions_for_y(
+    sensors: &[Sensor],
+    distress_y: i32,
+    exclusions: &mut Exclusions,
+) -> Result<(), Error> {
+    exclusions.ranges.clear();
+
+    for Sensor { sensor, beacon } in sensors {
+        let too_far = || {
+            anyhow!(
+                "Sensor at {},{} reaches too far to keep track of",
+                sensor.x,
+                sensor.y
+            )
+        };
+
+        // distances are unsigned, since they can be too big for an i32 even when the coordinates aren't
+        let dist_to_beacon = sensor
+            .x
+            .abs_diff(beacon.x)
+            .checked_add(sensor.y.abs_diff(beacon.y))
+            .ok_or_else(too_far)?;
+        let dist_to_distress_y = sensor.y.abs_diff(distress_y);
+
+        let Some(exclusion_count) = dist_to_beacon.checked_sub(dist_to_distress_y) else {
+            continue;
+        };
+
+        let exclusion_count = i32::try_from(exclusion_count).map_err(|_| too_far())?;
+        let start = sensor.x.checked_sub(exclusion_count).ok_or_else(too_far)?;
+        let end = sensor
+            .x
+            .checked_add(exclusion_count)
+            .and_then(|end| end.checked_add(1))
+            .ok_or_else(too_far)?;
+
+        let exclusion = start..end;
+        exclusions.insert(exclusion);
+    }
+
+    Ok(())
+}
+
+fn make_inverse(ranges: &[Range<i32>], inverse_ranges: &mut Vec<Range<i32>>) {
+    inverse_ranges.clear();
+
+    let mut prev_end = i32::MIN;
+
+    for range in ranges {
+        if prev_end < range.start {
+            inverse_ranges.push(prev_end..range.start);
+            prev_end = range.end;
+        }
+    }
+
+    if prev_end < i32::MAX {
+        inverse_ranges.push(prev_end..i32::MAX);
+    }
+}
+
+fn overlaps(a: &Range<i32>, b: &Range<i32>) -> bool {
+    !(a.end <= b.start || a.start >= b.end)
+}
+
+// The first gap in the sensors' coverage of row distress_y within the search area, using exclusions and inclusions
+// as scratch space
+fn find_gap(
+    sensors: &[Sensor],
+    distress_y: i32,
+    max_val: i32,
+    exclusions: &mut Exclusions,
+    inclusions: &mut Vec<Range<i32>>,
+) -> Result<Option<Range<i32>>, Error> {
+    calc_exclusions_for_y(sensors, distress_y, exclusions)?;
+    // println!("{exclusions:?}");
+    make_inverse(&exclusions.ranges, inclusions);
+    // println!("{inclusions:?}");
+
+    let gap = inclusions
+        .iter()
+        .find(|range| overlaps(range, &(0..max_val + 1)))
+        .cloned();
+    Ok(gap)
+}
+
+// The first row with a gap (or an error) wins, in parallel as in serial
+#[cfg(not(feature = "parallel"))]
+fn find_distress_beacon(
+    sensors: &[Sensor],
+    max_val: i32,
+) -> Result<Option<(Range<i32>, i32)>, Error> {
+    let mut exclusions = Exclusions::default();
+    let mut inclusions = Vec::new();
+
+    (0..=max_val)
+        .find_map(|distress_y| {
+            let gap = find_gap(
+                sensors,
+                distress_y,
+                max_val,
+                &mut exclusions,
+                &mut inclusions,
+            );
+            gap.transpose().map(|gap| gap.map(|gap| (gap, distress_y)))
+        })
+        .transpose()
+}
+
+#[cfg(feature = "parallel")]
+fn find_distress_beacon(
+    sensors: &[Sensor],
+    max_val: i32,
+) -> Result<Option<(Range<i32>, i32)>, Error> {
+    (0..=max_val)
+        .into_par_iter()
+        .map_init(
+            || (Exclusions::default(), Vec::new()),
+            |(exclusions, inclusions), distress_y| {
+                let gap = find_gap(sensors, distress_y, max_val, exclusions, inclusions);
+                gap.transpose().map(|gap| gap.map(|gap| (gap, distress_y)))
+            },
+        )
+        .find_map_first(|found| found)
+        .transpose()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let re =
+        regex::Regex::new(r#"^Sensor at x=(\S+), y=(\S+): closest beacon is at x=(\S+), y=(\S+)$"#)
+            .unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let captures = re.captures(line).ok_or_else(|| {
+                let expected = "\"Sensor at x=X, y=Y: closest beacon is at x=X, y=Y\"";
+                ParseError::at(input, line, expected)
+            })?;
+            let coord = |idx: usize| parse_token(input, &captures[idx], "an integer coordinate");
+            Ok(Sensor {
+                sensor: Point {
+                    x: coord(1)?,
+                    y: coord(2)?,
+                },
+                beacon: Point {
+                    x: coord(3)?,
+                    y: coord(4)?,
+                },
+            })
+        })
+        .collect()
+}
+
+// The row and search area the puzzle asks about, for when they aren't given
+//
+// The example's sensors all sit inside its search area, while the real input's are spread over millions of positions,
+// so an input whose sensors fit in the example's search area is taken to be the example.
+fn puzzle_area(sensors: &[Sensor]) -> (i32, i32) {
+    let example_area = 0..=EXAMPLE_MAX_VAL;
+    let is_example = sensors.iter().all(|sensor| {
+        example_area.contains(&sensor.sensor.x) && example_area.contains(&sensor.sensor.y)
+    });
+
+    if is_example {
+        (EXAMPLE_DISTRESS_Y, EXAMPLE_MAX_VAL)
+    } else {
+        (DISTRESS_Y, MAX_VAL)
+    }
+}
+
+fn solve(
+    input: &str,
+    part: Part,
+    distress_y: Option<i32>,
+    max_val: Option<i32>,
+) -> Result<Answer, Error> {
+    let sensors = common::phase("parse", || parse(input))?;
+
+    let (puzzle_distress_y, puzzle_max_val) = puzzle_area(&sensors);
+    let distress_y = distress_y.unwrap_or(puzzle_distress_y);
+    let max_val = max_val.unwrap_or(puzzle_max_val);
+
+    if part == Part::One {
+        let mut exclusions = Exclusions::default();
+        calc_exclusions_for_y(&sensors, distress_y, &mut exclusions)?;
+        common::trace!("{exclusions:?}");
+
+        let excluded = exclusions
+            .ranges
+            .iter()
+            .map(|range| range.end as i64 - range.start as i64)
+            .sum::<i64>();
+
+        // a beacon a sensor found is certainly somewhere a beacon can be
+        let beacons = sensors
+            .iter()
+            .map(|sensor| sensor.beacon)
+            .filter(|beacon| beacon.y == distress_y && exclusions.contains(beacon.x))
+            .map(|beacon| beacon.x)
+            .collect::<HashSet<_>>();
+
+        Ok((excluded - beacons.len() as i64).into())
+    } else {
+        let (gap, distress_y) = find_distress_beacon(&sensors, max_val)?
+            .ok_or_else(|| Error::msg("Distress beacon not found"))?;
+
+        // the beacon has to be the only place left uncovered
+        ensure!(
+            gap.len() == 1,
+            "Row {distress_y} has a gap of {} positions, so the distress beacon could be anywhere in it",
+            gap.len()
+        );
+        let distress_x = gap.start;
+
+        common::verbose!("found: {distress_x},{distress_y}");
+        common::detail("distress_beacon", (distress_x, distress_y));
+        let frequency = distress_x as u64 * 4000000 + distress_y as u64;
+        Ok(frequency.into())
+    }
+}
+
+pub fn part1_with(input: &str, distress_y: i32) -> Result<Answer, Error> {
+    solve(input, Part::One, Some(distress_y), None)
+}
+
+pub fn part2_with(input: &str, max_val: i32) -> Result<Answer, Error> {
+    solve(input, Part::Two, None, Some(max_val))
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One, None, None)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two, None, None)
+}
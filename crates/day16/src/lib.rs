@@ -0,0 +1,315 @@
+use anyhow::{anyhow, ensure, Error};
+use common::{parse_token, Answer, ParseError, Part};
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+// None once every u8 has been handed out
+fn get_node_index<'a>(node_indices: &mut HashMap<&'a str, u8>, name: &'a str) -> Option<u8> {
+    if let Some(index) = node_indices.get(name) {
+        return Some(*index);
+    }
+
+    let next_index = u8::try_from(node_indices.len()).ok()?;
+    node_indices.insert(name, next_index);
+    Some(next_index)
+}
+
+#[derive(Debug)]
+pub struct Node<'a> {
+    pub name: &'a str,
+    pub idx: u8, // in order of first mention, which is also the order nodes come back from parse
+    pub flow_rate: u8,
+    pub edges: Vec<u8>,
+}
+
+// Network
+
+// Only the valves worth opening, and how many minutes it takes to walk between them
+//
+// Walking through a valve on the way somewhere doesn't open it, so the valves stuck shut are just so much tunnel.
+#[derive(Debug)]
+struct Network {
+    flow_rates: Vec<u32>,     // of each valve worth opening
+    distances: Vec<Vec<u32>>, // between valves worth opening, and from the start (which comes last)
+    start: usize,
+}
+
+const START: &str = "AA";
+
+impl Network {
+    fn new(nodes: &[Node]) -> Result<Self, Error> {
+        let start = nodes
+            .iter()
+            .position(|node| node.name == START)
+            .ok_or_else(|| anyhow!("No valve {START} to start at"))?;
+
+        let valves = nodes
+            .iter()
+            .filter(|node| node.flow_rate > 0)
+            .map(|node| node.idx as usize)
+            .collect::<Vec<_>>();
+        ensure!(
+            valves.len() <= u64::BITS as usize,
+            "{} valves are worth opening, but only {} can be kept track of",
+            valves.len(),
+            u64::BITS
+        );
+
+        // Floyd-Warshall over every valve, with u32::MAX for no way there
+        let n = nodes.len();
+        let mut all = vec![vec![u32::MAX; n]; n];
+        for node in nodes {
+            let from = node.idx as usize;
+            all[from][from] = 0;
+            for to in &node.edges {
+                all[from][*to as usize] = 1;
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    let through = all[i][k].saturating_add(all[k][j]);
+                    if through < all[i][j] {
+                        all[i][j] = through;
+                    }
+                }
+            }
+        }
+
+        let kept = valves.iter().copied().chain([start]).collect::<Vec<_>>();
+        let distances = kept
+            .iter()
+            .map(|from| kept.iter().map(|to| all[*from][*to]).collect())
+            .collect();
+
+        Ok(Self {
+            flow_rates: valves
+                .iter()
+                .map(|valve| nodes[*valve].flow_rate as u32)
+                .collect(),
+            distances,
+            start: valves.len(),
+        })
+    }
+}
+
+// Search
+
+// The most pressure one explorer can release in the time given, for each set of valves it could open
+//
+// Sets are bitmasks over the valves worth opening. Every order they could be opened in is walked, but an order that
+// ends up at the same valve with the same ones open as an earlier one, with no more time left and no more released,
+// can't lead anywhere better, so it's cut short.
+fn releases(network: &Network, minutes: u32) -> HashMap<u64, u32> {
+    let mut walk = Walk::new(network, false);
+    walk.visit(network.start, minutes, 0, 0);
+    walk.best
+}
+
+// The most pressure one explorer can release in the time given, whatever it opens
+//
+// This is the same walk, but since only the best set matters, orders that can't beat the best found so far are cut short
+// too.
+fn most_released(network: &Network, minutes: u32) -> u32 {
+    let mut walk = Walk::new(network, true);
+    walk.visit(network.start, minutes, 0, 0);
+    walk.most
+}
+
+struct Walk<'a> {
+    network: &'a Network,
+    bounded: bool,           // whether to cut off orders that can't beat most
+    best: HashMap<u64, u32>, // by the valves opened
+    most: u32,               // of any set
+    seen: HashMap<(usize, u64), Vec<(u32, u32)>>, // time left and released, by valve and the valves opened
+}
+
+impl<'a> Walk<'a> {
+    fn new(network: &'a Network, bounded: bool) -> Self {
+        Self {
+            network,
+            bounded,
+            best: HashMap::new(),
+            most: 0,
+            seen: HashMap::new(),
+        }
+    }
+
+    // How long it takes to walk from valve to next and open it, or None if that's no use in the time left
+    fn open_time(&self, valve: usize, next: usize, time_left: u32, opened: u64) -> Option<u32> {
+        let elapsed = self.network.distances[valve][next].saturating_add(1);
+        (opened & (1 << next) == 0 && elapsed < time_left).then_some(elapsed)
+    }
+
+    // More than could be released from here, as if every valve still shut could be walked to straight from here
+    fn upper_bound(&self, valve: usize, time_left: u32, opened: u64, released: u32) -> u32 {
+        let flow_rates = self.network.flow_rates.iter().enumerate();
+        released
+            + flow_rates
+                .filter_map(|(next, flow_rate)| {
+                    let elapsed = self.open_time(valve, next, time_left, opened)?;
+                    Some(flow_rate * (time_left - elapsed))
+                })
+                .sum::<u32>()
+    }
+
+    fn visit(&mut self, valve: usize, time_left: u32, opened: u64, released: u32) {
+        if self.bounded && self.upper_bound(valve, time_left, opened, released) <= self.most {
+            return;
+        }
+
+        let seen = self.seen.entry((valve, opened)).or_default();
+        if seen.iter().any(|(t, r)| *t >= time_left && *r >= released) {
+            return;
+        }
+        seen.retain(|(t, r)| *t > time_left || *r > released);
+        seen.push((time_left, released));
+
+        let best = self.best.entry(opened).or_default();
+        *best = (*best).max(released);
+        self.most = self.most.max(released);
+
+        for (next, flow_rate) in self.network.flow_rates.iter().enumerate() {
+            if let Some(elapsed) = self.open_time(valve, next, time_left, opened) {
+                let remaining = time_left - elapsed;
+                let released = released + flow_rate * remaining;
+                self.visit(next, remaining, opened | 1 << next, released);
+            }
+        }
+    }
+}
+
+// The most two explorers can release together, each opening a set of valves the other doesn't
+//
+// Going through the sets from the best release down, a pair can be no better than twice the first's release, or
+// than the first's plus the second's, so the search stops as soon as neither could beat the best pair found.
+fn best_disjoint_pair(releases: &HashMap<u64, u32>) -> u32 {
+    let mut releases = releases.iter().collect::<Vec<_>>();
+    releases.sort_unstable_by_key(|(_, released)| Reverse(**released));
+
+    let mut best = 0;
+
+    for (i, (a, released_a)) in releases.iter().enumerate() {
+        if **released_a * 2 <= best {
+            break;
+        }
+
+        for (b, released_b) in &releases[i..] {
+            let together = **released_a + **released_b;
+            if together <= best {
+                break;
+            }
+
+            if **a & **b == 0 {
+                best = together;
+            }
+        }
+    }
+
+    best
+}
+
+pub fn parse<'a>(input: &'a str) -> Result<Vec<Node<'a>>, ParseError> {
+    let re = Regex::new(
+        r#"^Valve (\S+) has flow rate=(\S+); tunnels? leads? to valves? (\S+(?:, \S+)*)$"#,
+    )
+    .unwrap();
+    let mut node_indices = HashMap::new();
+
+    let mut nodes = input
+        .lines()
+        .map(|line| {
+            let captures = re.captures(line).ok_or_else(|| {
+                let expected = "\"Valve XX has flow rate=N; tunnels lead to valves YY, ZZ\"";
+                ParseError::at(input, line, expected)
+            })?;
+
+            let mut node_index = |name: &'a str| {
+                get_node_index(&mut node_indices, name)
+                    .ok_or_else(|| ParseError::at(input, name, "one of at most 256 valves"))
+            };
+
+            let name = captures.get(1).unwrap().as_str();
+            let idx = node_index(name)?;
+            let flow_rate = parse_token(input, &captures[2], "a flow rate (0-255)")?;
+            let edges = captures
+                .get(3)
+                .unwrap()
+                .as_str()
+                .split(", ")
+                .map(node_index)
+                .collect::<Result<_, _>>()?;
+
+            Ok(Node {
+                name,
+                idx,
+                flow_rate,
+                edges,
+            })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    nodes.sort_by_key(|node| node.idx);
+
+    // each valve described exactly once, including every one a tunnel leads to
+    for pair in nodes.windows(2) {
+        if pair[0].idx == pair[1].idx {
+            return Err(ParseError::at(
+                input,
+                pair[1].name,
+                "a valve not described before",
+            ));
+        }
+    }
+
+    if nodes.len() < node_indices.len() {
+        let (missing, _) = node_indices
+            .iter()
+            .find(|(_, idx)| {
+                nodes
+                    .get(**idx as usize)
+                    .is_none_or(|node| node.idx != **idx)
+            })
+            .expect("Some valve is missing");
+        let expected = format!("a line describing valve {missing}");
+        return Err(ParseError::at_end(input, expected));
+    }
+
+    Ok(nodes)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let nodes = common::phase("parse", || parse(input))?;
+    common::trace!("{nodes:?}");
+
+    let network = common::phase("preprocess", || Network::new(&nodes))?;
+    common::verbose!("{} valves are worth opening", network.flow_rates.len());
+    common::trace!("{network:?}");
+
+    // part 2 spends four minutes teaching an elephant to help, which then opens valves alongside
+    let minutes = match part {
+        Part::One => 30,
+        Part::Two => 26,
+    };
+
+    let released = match part {
+        Part::One => most_released(&network, minutes),
+        Part::Two => {
+            let releases = releases(&network, minutes);
+            common::detail("valve_sets", releases.len());
+            best_disjoint_pair(&releases)
+        }
+    };
+
+    Ok(released.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,211 @@
+use anyhow::{bail, Error};
+use common::image::{self, Image};
+use common::{Answer, ParseError, Part, Step};
+use geom::{Point2, Vector2};
+use grid::Grid;
+use std::fmt;
+
+// Pos
+
+// Bottom-left of a rock, with y growing upwards from the floor
+type Pos = Point2<i32>;
+
+// Move
+
+#[derive(Clone, Copy, Debug)]
+pub enum Move {
+    Left,
+    Right,
+    Down,
+}
+
+impl Move {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '<' => Some(Self::Left),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    fn vector(self) -> Vector2<i32> {
+        match self {
+            Self::Left => Vector2::new(-1, 0),
+            Self::Right => Vector2::new(1, 0),
+            Self::Down => Vector2::new(0, -1),
+        }
+    }
+}
+
+// Rock
+
+#[derive(Clone, Copy, Debug)]
+struct Rock([u8; 4]);
+
+impl Rock {
+    const ALL: [Rock; 5] = [
+        // 1 row per u8
+        // elem 0 is y = 0, elem 4 is y = 4
+        // lsb is x = 0, msb is x = 7
+        // shape is aligned to bottom-left
+        Rock([0b1111, 0b0000, 0b0000, 0b0000]), // -
+        Rock([0b0010, 0b0111, 0b0010, 0b0000]), // +
+        Rock([0b0111, 0b0100, 0b0100, 0b0000]), // L backwards
+        Rock([0b0001, 0b0001, 0b0001, 0b0001]), // |
+        Rock([0b0011, 0b0011, 0b0000, 0b0000]), // box
+    ];
+}
+
+// Chamber
+
+const CHAMBER_WIDTH: usize = 7;
+
+// Bottom row first, and only as tall as the highest rock
+struct Chamber(Grid<bool>);
+
+impl Default for Chamber {
+    fn default() -> Self {
+        Self(Grid::new(CHAMBER_WIDTH, 0))
+    }
+}
+
+impl fmt::Debug for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let render = self
+            .0
+            .render(|rock, f| f.write_str(if *rock { "#" } else { "." }))
+            .bottom_up();
+        write!(f, "{render}")
+    }
+}
+
+impl Chamber {
+    fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    // The chamber positions covered by a rock at pos, or None where it would be outside the chamber
+    fn rock_cells(rock: Rock, pos: Pos) -> impl Iterator<Item = Option<(usize, usize)>> {
+        rock.0
+            .into_iter()
+            .enumerate()
+            .flat_map(move |(yoff, rock_row)| {
+                (0..8)
+                    .filter(move |xoff| rock_row & (1 << xoff) != 0)
+                    .map(move |xoff| {
+                        let x = usize::try_from(pos.x + xoff).ok()?;
+                        let y = usize::try_from(pos.y + yoff as i32).ok()?;
+                        (x < CHAMBER_WIDTH).then_some((x, y))
+                    })
+            })
+    }
+
+    fn collides(&self, rock: Rock, pos: Pos) -> bool {
+        // hitting a wall or the floor is a collision, and anything above the top row is empty
+        Self::rock_cells(rock, pos).any(|cell| match cell {
+            Some((x, y)) => self.0.get(x, y).copied().unwrap_or(false),
+            None => true,
+        })
+    }
+
+    fn place(&mut self, rock: Rock, pos: Pos) {
+        for (x, y) in Self::rock_cells(rock, pos).flatten() {
+            // make space in chamber
+            while y >= self.0.height() {
+                self.0
+                    .push_row([false; CHAMBER_WIDTH])
+                    .expect("Row matches chamber width");
+            }
+
+            self.0[(x, y)] = true;
+        }
+    }
+
+    // Drawn floor side down, like the Debug rendering
+    fn to_image(&self) -> Image {
+        const SCALE: usize = 4;
+        let height = self.height();
+        let mut image = Image::new(CHAMBER_WIDTH * SCALE, height * SCALE, image::BLACK);
+
+        for ((x, y), rock) in self.0.iter() {
+            if *rock {
+                let top = (height - 1 - y) * SCALE;
+                image.fill_rect(x * SCALE, top, SCALE, SCALE, [170, 120, 80]);
+            }
+        }
+
+        image
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::at_end(input, "a line of jets"))?;
+
+    line.char_indices()
+        .map(|(idx, ch)| {
+            Move::from_char(ch).ok_or_else(|| ParseError::at(input, &line[idx..], "'<' or '>'"))
+        })
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let moves = common::phase("parse", || parse(input))?;
+
+    if part == Part::Two {
+        bail!("Part 2 is not implemented");
+    }
+
+    // println!("{moves:?}");
+
+    let mut chamber = Chamber::default();
+    let mut rock_iter = Rock::ALL.iter().copied().cycle();
+    let mut move_iter = moves.iter().copied().cycle().flat_map(|m| [m, Move::Down]);
+
+    for rock_num in 0..2022 {
+        let rock = rock_iter.next().unwrap();
+        // println!("{rock:?}");
+
+        // "Each rock appears so that its left edge is two units away from the left wall and its bottom edge is three
+        // units above the highest rock in the room (or the floor, if there isn't one)."
+        let mut pos = Pos {
+            x: 2,
+            y: chamber.height() as i32 + 3,
+        };
+
+        loop {
+            let move_ = move_iter.next().unwrap();
+            // println!("{move_:?}");
+
+            let new_pos = pos + move_.vector();
+
+            if chamber.collides(rock, new_pos) {
+                if matches!(move_, Move::Down) {
+                    chamber.place(rock, pos);
+                    break;
+                }
+            } else {
+                pos = new_pos;
+            }
+        }
+
+        // println!("{chamber:?}");
+        image::frame("day17-chamber", || chamber.to_image())?;
+        common::step(|| Step::new(format!("rock {}", rock_num + 1), &chamber))?;
+    }
+
+    image::still("day17-chamber", || chamber.to_image())?;
+    Ok(chamber.height().into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
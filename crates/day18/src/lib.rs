@@ -0,0 +1,140 @@
+use anyhow::{ensure, Error};
+use common::{parse_token, Answer, ParseError, Part};
+use geom::{BoundingBox3, Point3};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub type Position = Point3<i32>;
+
+// More air than this around the droplet is refused rather than flooded one position at a time; the real droplet has
+// about 14 thousand
+const MAX_FLOOD: u64 = 1 << 22;
+
+pub fn parse(input: &str) -> Result<HashSet<Position>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let tokens = line.split(',').collect::<Vec<_>>();
+            let (x, y, z) = match tokens[..] {
+                [x, y, z] => (x, y, z),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "three coordinates like \"1,2,3\"",
+                    ))
+                }
+            };
+
+            // kept well within an i32, so stepping to a neighbor never overflows
+            let coord = |token| -> Result<i32, ParseError> {
+                let coord = parse_token::<i16>(input, token, "a coordinate from -32768 to 32767")?;
+                Ok(coord.into())
+            };
+
+            Ok(Position {
+                x: coord(x)?,
+                y: coord(y)?,
+                z: coord(z)?,
+            })
+        })
+        .collect()
+}
+
+// Every position within bounds reachable from its corner without passing through a cube
+fn flood_exterior(cubes: &HashSet<Position>, bounds: BoundingBox3<i32>) -> HashSet<Position> {
+    let mut flood_stack = vec![bounds.min];
+    let mut exterior_cells = HashSet::new();
+
+    while let Some(cube) = flood_stack.pop() {
+        if !exterior_cells.insert(cube) {
+            continue;
+        }
+
+        for adjacent_cube in cube.neighbors6() {
+            if cubes.contains(&adjacent_cube) {
+                continue;
+            }
+
+            if !bounds.contains(adjacent_cube) {
+                continue;
+            }
+
+            flood_stack.push(adjacent_cube);
+        }
+    }
+
+    exterior_cells
+}
+
+// The number of cube faces whose neighbor matches f
+fn count_faces<F>(cubes: &HashSet<Position>, f: F) -> usize
+where
+    F: Fn(&Position) -> bool + Sync,
+{
+    let faces = |cube: &Position| {
+        cube.neighbors6()
+            .iter()
+            .filter(|adjacent| f(adjacent))
+            .count()
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    return cubes.iter().map(faces).sum();
+
+    #[cfg(feature = "parallel")]
+    return cubes.par_iter().map(faces).sum();
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let cubes = common::phase("parse", || parse(input))?;
+
+    common::trace!("{cubes:?}");
+
+    if part == Part::One {
+        let num_adjacent = count_faces(&cubes, |adjacent| cubes.contains(adjacent));
+
+        common::verbose!("num_total = {}", cubes.len() * 6);
+        common::verbose!("num_hidden = {num_adjacent}");
+
+        Ok((cubes.len() * 6 - num_adjacent).into())
+    } else {
+        let bounds = BoundingBox3::from_points(cubes.iter().copied())
+            .ok_or_else(|| Error::msg("No cubes in input"))?;
+
+        common::verbose!("min_val = {:?}", bounds.min);
+        common::verbose!("max_val = {:?}", bounds.max);
+
+        // flood the air around the droplet, leaving a layer of air on every side so it can get all the way around
+        let bounds = bounds.grow(1);
+        let volume = [
+            bounds.max.x - bounds.min.x,
+            bounds.max.y - bounds.min.y,
+            bounds.max.z - bounds.min.z,
+        ]
+        .iter()
+        .map(|extent| *extent as u64 + 1)
+        .product::<u64>();
+        ensure!(
+            volume <= MAX_FLOOD,
+            "The droplet spans {volume} positions, more than the {MAX_FLOOD} that can be flooded"
+        );
+
+        let exterior_cells = common::phase("preprocess", || flood_exterior(&cubes, bounds));
+
+        common::trace!("{exterior_cells:?}");
+
+        let num_exterior = count_faces(&cubes, |adjacent| exterior_cells.contains(adjacent));
+
+        Ok(num_exterior.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,252 @@
+use anyhow::Error;
+use common::{Answer, ParseError, Part};
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, multispace1, u32};
+use nom::combinator::{cut, eof};
+use nom::error::{context, VerboseError};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, terminated};
+
+type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+// Resource
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    const ALL: [Self; 4] = [Self::Ore, Self::Clay, Self::Obsidian, Self::Geode];
+}
+
+// Blueprint
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Blueprint {
+    pub id: u32,
+    pub costs: [[u32; 3]; 4], // the ore, clay and obsidian each robot costs, indexed by the Resource it collects
+}
+
+// What's been built and collected, geodes aside
+//
+// Geode robots aren't kept track of; each adds every geode it'll open to the count as soon as it's built.
+#[derive(Clone, Copy, Debug)]
+struct State {
+    time_left: u32, // in minutes
+    robots: [u32; 3],
+    resources: [u32; 3],
+    geodes: u32, // opened by the time the time's up
+}
+
+impl State {
+    // The state just after building robot, having waited to collect what it costs
+    //
+    // None if what it costs isn't being collected, or if the time's up before the robot can be any use.
+    fn build(self, robot: Resource, costs: &[u32; 3]) -> Option<Self> {
+        let mut wait = 0;
+
+        for (i, cost) in costs.iter().enumerate() {
+            if *cost > self.resources[i] {
+                if self.robots[i] == 0 {
+                    return None;
+                }
+
+                wait = u32::max(wait, (cost - self.resources[i]).div_ceil(self.robots[i]));
+            }
+        }
+
+        // building takes a minute, and the robot starts collecting the minute after that
+        let elapsed = wait + 1;
+        if elapsed >= self.time_left {
+            return None;
+        }
+
+        let mut next = self;
+        next.time_left -= elapsed;
+
+        for (i, cost) in costs.iter().enumerate() {
+            next.resources[i] = next.resources[i] + next.robots[i] * elapsed - cost;
+        }
+
+        match robot {
+            Resource::Geode => next.geodes += next.time_left,
+            _ => next.robots[robot as usize] += 1,
+        }
+
+        Some(next)
+    }
+
+    // More geodes than could possibly be opened from here
+    //
+    // This pretends ore and clay are free, so that an obsidian robot can be built every minute, along with a geode
+    // robot whenever there's the obsidian for it.
+    fn upper_bound(self, geode_obsidian: u32) -> u32 {
+        let mut obsidian = self.resources[Resource::Obsidian as usize];
+        let obsidian_robots = self.robots[Resource::Obsidian as usize];
+        let mut geodes = self.geodes;
+
+        for (minute, time_left) in (0..self.time_left).rev().enumerate() {
+            if obsidian >= geode_obsidian {
+                obsidian -= geode_obsidian;
+                geodes += time_left;
+            }
+
+            // one more obsidian robot for each minute gone by
+            obsidian += obsidian_robots + minute as u32;
+        }
+
+        geodes
+    }
+}
+
+impl Blueprint {
+    // The most geodes that can be opened in the time given, starting with one ore robot
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        // only one robot can be built a minute, so collecting more of something a minute than any robot costs is a
+        // waste
+        let mut max_robots = [0; 3];
+        for costs in &self.costs {
+            for i in 0..3 {
+                max_robots[i] = max_robots[i].max(costs[i]);
+            }
+        }
+
+        let start = State {
+            time_left: minutes,
+            robots: [1, 0, 0],
+            resources: [0; 3],
+            geodes: 0,
+        };
+
+        let mut best = 0;
+        self.search(start, &max_robots, &mut best);
+        best
+    }
+
+    // Branch and bound over which robot to build next
+    //
+    // Geode robots are tried first, since a good answer early on lets the bound cut off more.
+    fn search(&self, state: State, max_robots: &[u32; 3], best: &mut u32) {
+        *best = (*best).max(state.geodes);
+
+        let geode_obsidian = self.costs[Resource::Geode as usize][Resource::Obsidian as usize];
+        if state.upper_bound(geode_obsidian) <= *best {
+            return;
+        }
+
+        for robot in Resource::ALL.into_iter().rev() {
+            let idx = robot as usize;
+            if robot != Resource::Geode && state.robots[idx] >= max_robots[idx] {
+                continue;
+            }
+
+            if let Some(next) = state.build(robot, &self.costs[idx]) {
+                self.search(next, max_robots, best);
+            }
+        }
+    }
+}
+
+// Parsing
+
+fn gap(input: &str) -> IResult<&str, &str> {
+    context("a space or new line", multispace1)(input)
+}
+
+fn blueprint(input: &str) -> IResult<&str, Blueprint> {
+    let (input, _) = context("\"Blueprint \"", tag("Blueprint "))(input)?;
+    // anything that starts like a blueprint has to be one, so errors are reported where they happen
+    cut(blueprint_body)(input)
+}
+
+fn blueprint_body(input: &str) -> IResult<&str, Blueprint> {
+    let (input, id) = context("a blueprint number", u32)(input)?;
+    let (input, _) = context("':'", tag(":"))(input)?;
+    let (input, _) = gap(input)?;
+
+    let (input, _) = context("\"Each ore robot\"", tag("Each ore robot costs "))(input)?;
+    let (input, ore_ore) = context("an amount of ore", u32)(input)?;
+    let (input, _) = context("\" ore.\"", tag(" ore."))(input)?;
+    let (input, _) = gap(input)?;
+
+    let (input, _) = context("\"Each clay robot\"", tag("Each clay robot costs "))(input)?;
+    let (input, clay_ore) = context("an amount of ore", u32)(input)?;
+    let (input, _) = context("\" ore.\"", tag(" ore."))(input)?;
+    let (input, _) = gap(input)?;
+
+    let (input, _) = context("\"Each obsidian robot\"", tag("Each obsidian robot costs "))(input)?;
+    let (input, obsidian_ore) = context("an amount of ore", u32)(input)?;
+    let (input, _) = context("\" ore and \"", tag(" ore and "))(input)?;
+    let (input, obsidian_clay) = context("an amount of clay", u32)(input)?;
+    let (input, _) = context("\" clay.\"", tag(" clay."))(input)?;
+    let (input, _) = gap(input)?;
+
+    let (input, _) = context("\"Each geode robot\"", tag("Each geode robot costs "))(input)?;
+    let (input, geode_ore) = context("an amount of ore", u32)(input)?;
+    let (input, _) = context("\" ore and \"", tag(" ore and "))(input)?;
+    let (input, geode_obsidian) = context("an amount of obsidian", u32)(input)?;
+    let (input, _) = context("\" obsidian.\"", tag(" obsidian."))(input)?;
+
+    let costs = [
+        [ore_ore, 0, 0],
+        [clay_ore, 0, 0],
+        [obsidian_ore, obsidian_clay, 0],
+        [geode_ore, 0, geode_obsidian],
+    ];
+
+    Ok((input, Blueprint { id, costs }))
+}
+
+// Blueprints are usually one to a line, but can be wrapped like the puzzle's example
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let end = context("end of input", pair(multispace0, eof));
+    match terminated(separated_list1(multispace1, blueprint), end)(input) {
+        Ok((_input, blueprints)) => Ok(blueprints),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let blueprints = common::phase("parse", || parse(input))?;
+
+    // part 2 has more time, but the elephants ate all but the first three blueprints
+    let (minutes, count) = match part {
+        Part::One => (24, blueprints.len()),
+        Part::Two => (32, 3),
+    };
+
+    let geodes = blueprints
+        .iter()
+        .take(count)
+        .map(|blueprint| {
+            let geodes = blueprint.max_geodes(minutes);
+            common::verbose!("blueprint {}: {geodes} geodes", blueprint.id);
+            geodes
+        })
+        .collect::<Vec<_>>();
+
+    common::detail("geodes", &geodes);
+
+    let answer = match part {
+        Part::One => blueprints
+            .iter()
+            .zip(&geodes)
+            .map(|(blueprint, geodes)| blueprint.id as u64 * *geodes as u64)
+            .sum::<u64>(),
+        Part::Two => geodes.iter().map(|geodes| *geodes as u64).product::<u64>(),
+    };
+
+    Ok(answer.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,71 @@
+use anyhow::{anyhow, ensure, Error};
+use common::{parse_token, Answer, ParseError, Part};
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_token(input, line, "an integer"))
+        .collect()
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut coords = common::phase("parse", || parse(input))?
+        .into_iter()
+        .enumerate()
+        .collect::<Vec<_>>();
+
+    // a lone number has nowhere to move to
+    ensure!(coords.len() > 1, "Expected at least two numbers to mix");
+
+    let num_rounds = if part == Part::Two {
+        for (_, coord) in &mut coords {
+            *coord = coord
+                .checked_mul(811589153)
+                .ok_or_else(|| anyhow!("Number {coord} is too large to decrypt"))?;
+        }
+
+        10
+    } else {
+        1
+    };
+
+    for round_idx in 0..num_rounds {
+        common::verbose!("round {round_idx}");
+
+        for orig_idx in 0..coords.len() {
+            let cur_idx = coords.iter().position(|(oi, _)| *oi == orig_idx).unwrap();
+            let move_amt = coords[cur_idx].1;
+
+            let next_idx = (cur_idx as i64)
+                .wrapping_add(move_amt)
+                .rem_euclid(coords.len() as i64 - 1) as usize;
+
+            let tmp = coords.remove(cur_idx);
+            coords.insert(next_idx, tmp);
+        }
+    }
+
+    let zero_idx = coords
+        .iter()
+        .position(|(_, coord)| *coord == 0)
+        .ok_or_else(|| anyhow!("No number 0 to count from"))?;
+    let a = coords[(zero_idx + 1000) % coords.len()].1;
+    let b = coords[(zero_idx + 2000) % coords.len()].1;
+    let c = coords[(zero_idx + 3000) % coords.len()].1;
+    common::verbose!("{a} {b} {c}");
+
+    let sum = a
+        .checked_add(b)
+        .and_then(|sum| sum.checked_add(c))
+        .ok_or_else(|| anyhow!("Grove coordinates {a}, {b} and {c} add up to too much"))?;
+
+    Ok(sum.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
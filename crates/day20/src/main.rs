@@ -0,0 +1,5 @@
+use anyhow::Error;
+
+fn main() -> Result<(), Error> {
+    common::run(20, day20::part1, day20::part2)
+}
@@ -0,0 +1,411 @@
+use anyhow::{anyhow, bail, ensure, Error};
+use common::{Answer, ParseError, Part};
+use core::fmt;
+use nom::branch::alt;
+use nom::character::complete::{alpha1, char, multispace0, newline, one_of, space0, u64};
+use nom::combinator::{cut, eof};
+use nom::error::{context, VerboseError};
+use nom::multi::many1;
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Div, Mul, Sub};
+
+type Prim = ruint::aliases::U4096;
+
+fn gcd(a: Prim, b: Prim) -> Prim {
+    if a == Prim::from(0) {
+        b
+    } else {
+        gcd(b % a, a)
+    }
+}
+
+fn lcm(a: Prim, b: Prim) -> Prim {
+    (a * b) / gcd(a, b)
+}
+
+#[derive(Clone, Copy)]
+pub struct Fraction {
+    numer: Prim,
+    denom: Prim,
+}
+
+impl fmt::Debug for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denom != Prim::from(1) {
+            write!(f, "{}/{}", self.numer, self.denom)
+        } else {
+            write!(f, "{}", self.numer)
+        }
+    }
+}
+
+impl Fraction {
+    fn invert(self) -> Fraction {
+        Self {
+            numer: self.denom,
+            denom: self.numer,
+        }
+    }
+
+    fn reduce(self) -> Fraction {
+        let divisor = gcd(self.numer, self.denom);
+        Self {
+            numer: self.numer / divisor,
+            denom: self.denom / divisor,
+        }
+    }
+}
+
+impl Add for Fraction {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let denom = lcm(self.denom, other.denom);
+
+        let numer_self = self.numer * (denom / self.denom);
+        let numer_other = other.numer * (denom / other.denom);
+
+        Self {
+            numer: numer_self + numer_other,
+            denom,
+        }
+        .reduce()
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let denom = lcm(self.denom, other.denom);
+
+        let numer_self = self.numer * (denom / self.denom);
+        let numer_other = other.numer * (denom / other.denom);
+
+        Self {
+            numer: numer_self - numer_other,
+            denom,
+        }
+        .reduce()
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            numer: self.numer * other.numer,
+            denom: self.denom * other.denom,
+        }
+        .reduce()
+    }
+}
+
+impl Div for Fraction {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self.mul(other.invert()).reduce()
+    }
+}
+
+impl From<Fraction> for Answer {
+    fn from(value: Fraction) -> Self {
+        match u64::try_from(value.numer) {
+            Ok(numer) if value.denom == Prim::from(1) => numer.into(),
+            _ => format!("{value:?}").into(),
+        }
+    }
+}
+
+impl From<Prim> for Fraction {
+    fn from(value: Prim) -> Self {
+        Self {
+            numer: value,
+            denom: Prim::from(1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn eval(self, left: Fraction, right: Fraction, depth: u32) -> Fraction {
+        let res = match self {
+            Self::Add => left.add(right),
+            Self::Sub => left.sub(right),
+            Self::Mul => left.mul(right),
+            Self::Div => left.div(right),
+        };
+
+        let indent = depth as usize * 2;
+        common::trace!("{:indent$}{left:?} {self:?} {right:?} = {res:?}", "");
+
+        res
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Expr<'a> {
+    Const {
+        value: Fraction,
+    },
+    BinOp {
+        left: &'a str,
+        right: &'a str,
+        binop: BinOp,
+    },
+}
+
+type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+#[derive(Debug)]
+struct Line<'a> {
+    name: &'a str,
+    expr: Expr<'a>,
+}
+
+fn parse_expr_const(mut input: &str) -> IResult<&str, Expr<'_>> {
+    let value;
+    (input, value) = context("a number", u64)(input)?;
+    let value = Prim::from(value);
+    let value = Fraction::from(value);
+    Ok((input, Expr::Const { value }))
+}
+
+fn parse_expr_binop(mut input: &str) -> IResult<&str, Expr<'_>> {
+    let (left, binop, right);
+    (input, left) = context("a number or a monkey name", alpha1)(input)?;
+    (input, _) = space0(input)?;
+    (input, binop) = context("an operator (+, -, * or /)", one_of("+-*/"))(input)?;
+    (input, _) = space0(input)?;
+    (input, right) = context("a monkey name", alpha1)(input)?;
+
+    let binop = match binop {
+        '+' => BinOp::Add,
+        '-' => BinOp::Sub,
+        '*' => BinOp::Mul,
+        '/' => BinOp::Div,
+        _ => unreachable!(),
+    };
+
+    Ok((input, Expr::BinOp { left, right, binop }))
+}
+
+fn parse_expr(input: &str) -> IResult<&str, Expr<'_>> {
+    alt((parse_expr_const, cut(parse_expr_binop)))(input)
+}
+
+fn parse_line(mut input: &str) -> IResult<&str, Line<'_>> {
+    let (name, expr);
+    (input, name) = context("a monkey name", alpha1)(input)?;
+    (input, _) = cut(context("':'", char(':')))(input)?;
+    (input, _) = space0(input)?;
+    (input, expr) = parse_expr(input)?;
+    (input, _) = cut(context("end of line", newline))(input)?;
+    let line = Line { name, expr };
+    Ok((input, line))
+}
+
+fn parse_input(mut input: &str) -> IResult<&str, Vec<Line<'_>>> {
+    let lines;
+    (input, lines) = many1(parse_line)(input)?;
+    (input, _) = multispace0(input)?;
+    (input, _) = context("another monkey", eof)(input)?;
+    Ok((input, lines))
+}
+
+// Errors if a monkey root depends on is waiting on its own number, which no amount of resolving would find
+//
+// The monkeys are walked with a stack of their own rather than by recursion, so a long chain of them can't overflow it.
+fn check_acyclic(root: &str, exprs_by_name: &HashMap<&str, Expr>) -> Result<(), Error> {
+    let mut resolving = HashSet::new(); // the chain of monkeys from root to the one being looked at
+    let mut resolved = HashSet::new();
+    let mut stack = vec![(root, false)]; // with whether the monkeys it depends on have been pushed yet
+
+    while let Some((name, pushed)) = stack.pop() {
+        if pushed {
+            resolving.remove(name);
+            resolved.insert(name);
+            continue;
+        }
+
+        if resolved.contains(name) {
+            continue;
+        }
+
+        ensure!(resolving.insert(name), "Monkey {name} depends on itself");
+        stack.push((name, true));
+
+        if let Some(Expr::BinOp { left, right, .. }) = exprs_by_name.get(name) {
+            stack.push((left, false));
+            stack.push((right, false));
+        }
+    }
+
+    Ok(())
+}
+
+fn resolve(
+    name: &str,
+    exprs_by_name: &HashMap<&str, Expr>,
+    part: Part,
+    depth: u32,
+) -> Result<Fraction, Error> {
+    // in part 2, humn is the unknown we're solving for
+    ensure!(part == Part::One || name != "humn");
+
+    let expr = exprs_by_name
+        .get(name)
+        .ok_or_else(|| anyhow!("expr {name} not found"))?;
+
+    let result = match expr {
+        Expr::Const { value } => *value,
+        Expr::BinOp { left, right, binop } => {
+            let left = resolve(left, exprs_by_name, part, depth + 1)?;
+            let right = resolve(right, exprs_by_name, part, depth + 1)?;
+            binop.eval(left, right, depth + 1)
+        }
+    };
+
+    let indent = depth as usize * 2;
+    common::trace!("{:indent$}eval({name}) = {result:?}", "");
+    Ok(result)
+}
+
+fn expr_references(
+    name: &str,
+    search: &str,
+    exprs_by_name: &HashMap<&str, Expr>,
+) -> Result<bool, Error> {
+    if name == search {
+        return Ok(true);
+    }
+
+    let expr = exprs_by_name
+        .get(name)
+        .ok_or_else(|| anyhow!("expr {name} not found"))?;
+
+    let (left, right) = match expr {
+        Expr::Const { .. } => return Ok(false),
+        Expr::BinOp { left, right, .. } => (left, right),
+    };
+
+    Ok(expr_references(left, search, exprs_by_name)?
+        || expr_references(right, search, exprs_by_name)?)
+}
+
+fn converge(
+    name: &str,
+    target: Fraction,
+    exprs_by_name: &HashMap<&str, Expr>,
+    depth: u32,
+) -> Result<Fraction, Error> {
+    let expr = exprs_by_name
+        .get(name)
+        .ok_or_else(|| anyhow!("expr {name} not found"))?;
+
+    let res = match expr {
+        Expr::Const { .. } => {
+            // println!("converge const to {target}");
+            ensure!(name == "humn");
+            target
+        }
+        Expr::BinOp { left, right, binop } => {
+            if expr_references(left, "humn", exprs_by_name)? {
+                // left is free, right is fixed
+                let right = resolve(right, exprs_by_name, Part::Two, depth + 1)?;
+
+                let new_target = match binop {
+                    BinOp::Add => target - right,
+                    BinOp::Sub => target + right,
+                    BinOp::Mul => target / right,
+                    BinOp::Div => target * right,
+                };
+
+                converge(left, new_target, exprs_by_name, depth + 1)?
+            } else if expr_references(right, "humn", exprs_by_name)? {
+                // right is free, left is fixed
+                let left = resolve(left, exprs_by_name, Part::Two, depth + 1)?;
+
+                let new_target = match binop {
+                    BinOp::Add => target - left,
+                    BinOp::Sub => left - target,
+                    BinOp::Mul => target / left,
+                    BinOp::Div => left / target,
+                };
+
+                converge(right, new_target, exprs_by_name, depth + 1)?
+            } else {
+                bail!("humn not referenced by binop");
+            }
+        }
+    };
+
+    let indent = depth as usize * 2;
+    common::trace!("{:indent$}converge({name}, {target:?}) = {res:?}", "");
+    Ok(res)
+}
+
+// Maps each monkey's name to the expression it yells
+pub fn parse(input: &str) -> Result<HashMap<&str, Expr<'_>>, ParseError> {
+    let (_, lines) = parse_input(input).map_err(|err| ParseError::from_nom(input, err))?;
+
+    let mut exprs_by_name = HashMap::new();
+    for line in lines.into_iter() {
+        exprs_by_name.insert(line.name, line.expr);
+    }
+
+    Ok(exprs_by_name)
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let exprs_by_name = common::phase("parse", || parse(input))?;
+    check_acyclic("root", &exprs_by_name)?;
+
+    // println!("{exprs_by_name:?}");
+
+    if part == Part::One {
+        let r = resolve("root", &exprs_by_name, part, 0)?;
+        Ok(r.into())
+    } else {
+        let expr = exprs_by_name
+            .get("root")
+            .ok_or_else(|| anyhow!("expr root not found"))?;
+
+        let (left, right) = match expr {
+            Expr::Const { .. } => bail!("root is a constant"),
+            Expr::BinOp { left, right, .. } => (left, right),
+        };
+
+        let res = if expr_references(left, "humn", &exprs_by_name)? {
+            // left is free, right is fixed
+            let target = resolve(right, &exprs_by_name, part, 0)?;
+            converge(left, target, &exprs_by_name, 0)?
+        } else if expr_references(right, "humn", &exprs_by_name)? {
+            // right is free, left is fixed
+            let target = resolve(left, &exprs_by_name, part, 0)?;
+            converge(right, target, &exprs_by_name, 0)?
+        } else {
+            bail!("humn not referenced by binop");
+        };
+
+        Ok(res.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,33 @@
+use common::Answer;
+
+// aaaa and bbbb each wait on the other
+const CYCLE: &str = "\
+root: aaaa + bbbb
+aaaa: bbbb * cccc
+bbbb: aaaa + cccc
+cccc: 3
+humn: 5
+";
+
+#[test]
+fn cycle_is_an_error() {
+    for solve in [day21::part1, day21::part2] {
+        let err = solve(CYCLE).unwrap_err();
+        assert!(err.to_string().contains("depends on itself"), "{err:#}");
+    }
+}
+
+#[test]
+fn monkey_waiting_on_itself_is_an_error() {
+    let input = "root: humn + aaaa\naaaa: aaaa * humn\nhumn: 5\n";
+    assert!(day21::part1(input).is_err());
+    assert!(day21::part2(input).is_err());
+}
+
+// a monkey both sides wait on is shared, not a cycle
+#[test]
+fn shared_monkey_is_fine() {
+    let input = "root: aaaa + bbbb\naaaa: cccc * cccc\nbbbb: cccc + humn\ncccc: 3\nhumn: 5\n";
+    assert_eq!(day21::part1(input).unwrap(), Answer::Int(17));
+    assert_eq!(day21::part2(input).unwrap(), Answer::Int(6));
+}
@@ -0,0 +1,485 @@
+use anyhow::{ensure, Error};
+use common::{parse_token, Answer, ParseError, Part, Step};
+use geom::{Direction, Vector3};
+use grid::Grid;
+use std::fmt;
+
+// Tile
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Tile {
+    #[default]
+    Void, // off the map
+    Open,
+    Wall,
+}
+
+impl Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            ' ' => Some(Self::Void),
+            '.' => Some(Self::Open),
+            '#' => Some(Self::Wall),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self::Void => ' ',
+            Self::Open => '.',
+            Self::Wall => '#',
+        }
+    }
+}
+
+// Instruction
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Forward(u32),
+    TurnLeft,
+    TurnRight,
+}
+
+// Notes
+
+#[derive(Debug)]
+pub struct Notes {
+    pub board: Grid<Tile>, // lines shorter than the widest are padded with Void
+    pub path: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let (map, path) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "a blank line, then the path"))?;
+
+    // board
+
+    let mut rows = Vec::new();
+
+    for line in map.lines() {
+        let mut row = Vec::new();
+
+        for (idx, ch) in line.char_indices() {
+            let tile = Tile::from_char(ch)
+                .ok_or_else(|| ParseError::at(input, &line[idx..], "' ', '.' or '#'"))?;
+            row.push(tile);
+        }
+
+        rows.push(row);
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return Err(ParseError::at(input, map, "a map"));
+    }
+
+    let mut cells = Vec::with_capacity(width * rows.len());
+    for mut row in rows {
+        row.resize(width, Tile::Void);
+        cells.extend(row);
+    }
+
+    let board = Grid::from_cells(width, cells).expect("Rows are padded to the same width");
+
+    // path
+
+    let path_text = path.trim_end();
+    let mut path = Vec::new();
+    let mut digits_start = None;
+
+    for (idx, ch) in path_text.char_indices() {
+        if ch.is_ascii_digit() {
+            digits_start.get_or_insert(idx);
+            continue;
+        }
+
+        if let Some(start) = digits_start.take() {
+            let count = parse_token(input, &path_text[start..idx], "a number of tiles")?;
+            path.push(Instruction::Forward(count));
+        }
+
+        match ch {
+            'L' => path.push(Instruction::TurnLeft),
+            'R' => path.push(Instruction::TurnRight),
+            _ => {
+                let expected = "a number of tiles, L or R";
+                return Err(ParseError::at(input, &path_text[idx..], expected));
+            }
+        }
+    }
+
+    if let Some(start) = digits_start {
+        let count = parse_token(input, &path_text[start..], "a number of tiles")?;
+        path.push(Instruction::Forward(count));
+    }
+
+    if path.is_empty() {
+        return Err(ParseError::at(input, path_text, "a path"));
+    }
+
+    Ok(Notes { board, path })
+}
+
+// Walker
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Walker {
+    pub position: (usize, usize),
+    pub facing: Direction,
+}
+
+impl Walker {
+    fn password(self) -> usize {
+        let (x, y) = self.position;
+        let facing = match self.facing {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        1000 * (y + 1) + 4 * (x + 1) + facing
+    }
+}
+
+// The tile next to (x, y) in direction dir, if it's on the board
+fn neighbor(board: &Grid<Tile>, (x, y): (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+    let offset = dir.vector::<i8>();
+    let x = x.checked_add_signed(offset.x.into())?;
+    let y = y.checked_add_signed(offset.y.into())?;
+
+    match board.get(x, y) {
+        Some(Tile::Open | Tile::Wall) => Some((x, y)),
+        _ => None,
+    }
+}
+
+// Face
+
+// One face of the cube, as it sits on the board and once folded
+//
+// The vectors say which way the face's outside, and the board's x and y within the face, point once folded.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    corner: (usize, usize), // top-left tile
+    normal: Vector3<i32>,
+    right: Vector3<i32>,
+    down: Vector3<i32>,
+}
+
+impl Face {
+    fn axis(&self, dir: Direction) -> Vector3<i32> {
+        match dir {
+            Direction::Up => -self.down,
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+        }
+    }
+
+    // The neighboring face on the board in direction dir, folded over the edge they share
+    fn fold(&self, dir: Direction, corner: (usize, usize)) -> Self {
+        let (n, r, d) = (self.normal, self.right, self.down);
+        let (normal, right, down) = match dir {
+            Direction::Up => (-d, r, n),
+            Direction::Right => (r, -n, d),
+            Direction::Down => (d, r, -n),
+            Direction::Left => (-r, n, d),
+        };
+
+        Self {
+            corner,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+// How far along a face's edge a position within the face is, going clockwise around it
+//
+// Folding doesn't mirror any face, so the two faces meeting at an edge go around it in opposite directions.
+fn edge_offset(size: usize, (x, y): (usize, usize), edge: Direction) -> usize {
+    match edge {
+        Direction::Up => x,
+        Direction::Right => y,
+        Direction::Down => size - 1 - x,
+        Direction::Left => size - 1 - y,
+    }
+}
+
+fn edge_position(size: usize, edge: Direction, offset: usize) -> (usize, usize) {
+    match edge {
+        Direction::Up => (offset, 0),
+        Direction::Right => (size - 1, offset),
+        Direction::Down => (size - 1 - offset, size - 1),
+        Direction::Left => (0, size - 1 - offset),
+    }
+}
+
+// Cube
+
+#[derive(Debug)]
+pub struct Cube {
+    size: usize, // of each face, in tiles
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    // Folds the board up into a cube, whichever of the cube's nets it's drawn as
+    pub fn fold(board: &Grid<Tile>) -> Result<Self, Error> {
+        let tiles = board
+            .iter()
+            .filter(|(_, tile)| **tile != Tile::Void)
+            .count();
+        let size = (tiles / 6).isqrt();
+        ensure!(
+            size > 0 && 6 * size * size == tiles,
+            "{tiles} tiles can't make six square faces"
+        );
+        ensure!(
+            board.width().is_multiple_of(size) && board.height().is_multiple_of(size),
+            "A {}x{} board doesn't divide into faces {size} tiles wide",
+            board.width(),
+            board.height()
+        );
+
+        // find the faces, each of which has to be all on or all off the board
+        let mut corners = Vec::new();
+
+        for corner_y in (0..board.height()).step_by(size) {
+            for corner_x in (0..board.width()).step_by(size) {
+                let on_board = (corner_y..corner_y + size)
+                    .flat_map(|y| (corner_x..corner_x + size).map(move |x| (x, y)))
+                    .filter(|&pos| board[pos] != Tile::Void)
+                    .count();
+
+                if on_board == size * size {
+                    corners.push((corner_x, corner_y));
+                } else {
+                    ensure!(
+                        on_board == 0,
+                        "The {size}x{size} square at ({corner_x}, {corner_y}) is only partly on the board"
+                    );
+                }
+            }
+        }
+
+        ensure!(corners.len() == 6, "The board has {} faces", corners.len());
+
+        // fold from the first face outwards, across the edges the board shows
+        let mut faces: Vec<Option<Face>> = vec![None; 6];
+        faces[0] = Some(Face {
+            corner: corners[0],
+            normal: Vector3::new(0, 0, 1),
+            right: Vector3::new(1, 0, 0),
+            down: Vector3::new(0, 1, 0),
+        });
+
+        let mut work_queue = vec![0];
+
+        while let Some(idx) = work_queue.pop() {
+            let face = faces[idx].expect("Queued faces are folded");
+
+            for dir in Direction::ALL {
+                let offset = dir.vector::<isize>() * size as isize;
+                let corner = face
+                    .corner
+                    .0
+                    .checked_add_signed(offset.x)
+                    .zip(face.corner.1.checked_add_signed(offset.y));
+
+                let Some(next) = corners.iter().position(|c| Some(*c) == corner) else {
+                    continue;
+                };
+
+                if faces[next].is_none() {
+                    faces[next] = Some(face.fold(dir, corners[next]));
+                    work_queue.push(next);
+                }
+            }
+        }
+
+        let faces = faces
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Error::msg("The faces aren't all connected"))?;
+
+        for (i, face) in faces.iter().enumerate() {
+            ensure!(
+                faces[..i].iter().all(|other| other.normal != face.normal),
+                "The faces overlap when folded, so they don't make a cube"
+            );
+        }
+
+        Ok(Self { size, faces })
+    }
+
+    fn face_at(&self, (x, y): (usize, usize)) -> &Face {
+        self.faces
+            .iter()
+            .find(|face| {
+                let (cx, cy) = face.corner;
+                (cx..cx + self.size).contains(&x) && (cy..cy + self.size).contains(&y)
+            })
+            .unwrap_or_else(|| panic!("Position ({x}, {y}) isn't on a face"))
+    }
+
+    // Where the walker ends up going over the edge of its face, on to the face that meets it there
+    fn wrap(&self, walker: Walker) -> Walker {
+        let face = self.face_at(walker.position);
+        let normal = face.axis(walker.facing);
+
+        let next = self
+            .faces
+            .iter()
+            .find(|next| next.normal == normal)
+            .expect("Every side of a cube has a face");
+        let edge = Direction::ALL
+            .into_iter()
+            .find(|dir| next.axis(*dir) == face.normal)
+            .expect("Neighboring faces share an edge");
+
+        let (x, y) = walker.position;
+        let (cx, cy) = face.corner;
+        let offset = edge_offset(self.size, (x - cx, y - cy), walker.facing);
+        let (nx, ny) = edge_position(self.size, edge, self.size - 1 - offset);
+
+        Walker {
+            position: (next.corner.0 + nx, next.corner.1 + ny),
+            facing: edge.reverse(),
+        }
+    }
+}
+
+// Wrap
+
+// What's past the edge of the board
+#[derive(Debug)]
+pub enum Wrap {
+    Flat,       // the other side of the same row or column
+    Cube(Cube), // the neighboring face of the folded cube
+}
+
+impl Wrap {
+    // The walker one tile ahead, whether or not that's a wall
+    pub fn ahead(&self, board: &Grid<Tile>, walker: Walker) -> Walker {
+        if let Some(position) = neighbor(board, walker.position, walker.facing) {
+            return Walker { position, ..walker };
+        }
+
+        match self {
+            Self::Flat => {
+                let back = walker.facing.reverse();
+                let mut position = walker.position;
+                while let Some(prev) = neighbor(board, position, back) {
+                    position = prev;
+                }
+
+                Walker { position, ..walker }
+            }
+            Self::Cube(cube) => cube.wrap(walker),
+        }
+    }
+}
+
+// Trail
+
+// Draws the board with the last way the walker faced on each tile it's been on, like the puzzle does
+struct Trail<'a> {
+    board: &'a Grid<Tile>,
+    trail: &'a Grid<Option<Direction>>,
+}
+
+impl fmt::Debug for Trail<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.board.height() {
+            for x in 0..self.board.width() {
+                let ch = match self.trail[(x, y)] {
+                    Some(Direction::Up) => '^',
+                    Some(Direction::Right) => '>',
+                    Some(Direction::Down) => 'v',
+                    Some(Direction::Left) => '<',
+                    None => self.board[(x, y)].to_char(),
+                };
+                write!(f, "{ch}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let notes = common::phase("parse", || parse(input))?;
+    let board = &notes.board;
+
+    let wrap = match part {
+        Part::One => Wrap::Flat,
+        Part::Two => Wrap::Cube(common::phase("preprocess", || Cube::fold(board))?),
+    };
+
+    let start = board
+        .row(0)
+        .iter()
+        .position(|tile| *tile == Tile::Open)
+        .ok_or_else(|| Error::msg("No open tile on the top row to start from"))?;
+
+    let mut walker = Walker {
+        position: (start, 0),
+        facing: Direction::Right,
+    };
+
+    let mut trail = Grid::new(board.width(), board.height());
+    trail[walker.position] = Some(walker.facing);
+
+    for (i, instruction) in notes.path.iter().enumerate() {
+        match *instruction {
+            Instruction::Forward(count) => {
+                for _ in 0..count {
+                    let next = wrap.ahead(board, walker);
+                    if board[next.position] == Tile::Wall {
+                        break;
+                    }
+
+                    walker = next;
+                    trail[walker.position] = Some(walker.facing);
+                }
+            }
+            Instruction::TurnLeft => walker.facing = walker.facing.turn_left(),
+            Instruction::TurnRight => walker.facing = walker.facing.turn_right(),
+        }
+
+        trail[walker.position] = Some(walker.facing);
+
+        common::step(|| {
+            let label = format!("{instruction:?} ({} of {})", i + 1, notes.path.len());
+            Step::new(
+                label,
+                &Trail {
+                    board,
+                    trail: &trail,
+                },
+            )
+        })?;
+    }
+
+    let (x, y) = walker.position;
+    common::detail("final_position", (x + 1, y + 1));
+    common::detail("final_facing", format!("{:?}", walker.facing));
+
+    Ok(walker.password().into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
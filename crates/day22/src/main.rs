@@ -0,0 +1,5 @@
+use anyhow::Error;
+
+fn main() -> Result<(), Error> {
+    common::run(22, day22::part1, day22::part2)
+}
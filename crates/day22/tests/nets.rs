@@ -0,0 +1,132 @@
+use day22::{Cube, Walker, Wrap};
+use geom::Direction;
+
+// The 11 nets of a cube, one # per face
+const NETS: &[&[&str]] = &[
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+// Every way of flipping and turning a net
+fn orientations(net: &[&str]) -> Vec<Vec<Vec<bool>>> {
+    let faces = net
+        .iter()
+        .map(|row| row.chars().map(|ch| ch == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let transpose = |faces: &Vec<Vec<bool>>| -> Vec<Vec<bool>> {
+        (0..faces[0].len())
+            .map(|x| faces.iter().map(|row| row[x]).collect())
+            .collect()
+    };
+
+    let mut all = Vec::new();
+    for faces in [faces.clone(), transpose(&faces)] {
+        for flip_y in [false, true] {
+            for flip_x in [false, true] {
+                let mut faces = faces.clone();
+                if flip_y {
+                    faces.reverse();
+                }
+                if flip_x {
+                    faces.iter_mut().for_each(|row| row.reverse());
+                }
+                all.push(faces);
+            }
+        }
+    }
+
+    all
+}
+
+// An open board drawn as the given faces, each size tiles across
+fn board(faces: &[Vec<bool>], size: usize) -> String {
+    let mut out = String::new();
+
+    for row in faces {
+        for _ in 0..size {
+            for &face in row {
+                out.push_str(&if face { "." } else { " " }.repeat(size));
+            }
+            out.push('\n');
+        }
+    }
+
+    out + "\n1\n"
+}
+
+#[test]
+fn walking_around_any_net_comes_back() {
+    let size = 3;
+
+    for net in NETS {
+        for faces in orientations(net) {
+            let input = board(&faces, size);
+            let board = day22::parse(&input).unwrap().board;
+            let wrap =
+                Wrap::Cube(Cube::fold(&board).unwrap_or_else(|err| panic!("{err}\n{input}")));
+
+            // once around the cube is four faces, from anywhere and facing any way
+            for (position, tile) in board.iter() {
+                if *tile == day22::Tile::Void {
+                    continue;
+                }
+
+                for facing in Direction::ALL {
+                    let start = Walker { position, facing };
+                    let mut walker = start;
+                    for _ in 0..4 * size {
+                        walker = wrap.ahead(&board, walker);
+                        assert_ne!(board[walker.position], day22::Tile::Void, "\n{input}");
+                    }
+
+                    assert_eq!(walker, start, "\n{input}");
+
+                    // and a step back the way it came undoes a step
+                    let ahead = wrap.ahead(&board, start);
+                    let back = Walker {
+                        facing: ahead.facing.reverse(),
+                        ..ahead
+                    };
+                    let back = wrap.ahead(&board, back);
+                    assert_eq!(back.position, start.position, "\n{input}");
+                    assert_eq!(back.facing, start.facing.reverse(), "\n{input}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn boards_that_arent_nets_dont_fold() {
+    let not_nets: &[&[&str]] = &[
+        &["######"],
+        &["###", "###"],
+        &["####.", "...##"],
+        &["##", "##", ".#", ".#"],
+        &["#..", "###", "..#", "..#"],
+    ];
+
+    for net in not_nets {
+        let faces = net
+            .iter()
+            .map(|row| row.chars().map(|ch| ch == '#').collect())
+            .collect::<Vec<_>>();
+        let input = board(&faces, 2);
+        let board = day22::parse(&input).unwrap().board;
+        assert!(Cube::fold(&board).is_err(), "\n{input}");
+    }
+
+    // faces have to be whole
+    let board = day22::parse("...\n..\n\n1\n").unwrap().board;
+    assert!(Cube::fold(&board).is_err());
+}
@@ -0,0 +1,159 @@
+use anyhow::Error;
+use common::{Answer, ParseError, Part, Step};
+use geom::{BoundingBox2, Direction, Point2};
+use grid::Grid;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// Pos
+
+// y grows downwards (south), as in the input; the elves spread out without bound, so there's no grid to size
+type Pos = Point2<i32>;
+
+// Elves
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Elves(pub HashSet<Pos>);
+
+impl Elves {
+    fn bounds(&self) -> Option<BoundingBox2<i32>> {
+        BoundingBox2::from_points(self.0.iter().copied())
+    }
+
+    // Empty ground in the smallest rectangle holding every elf
+    fn empty_ground(&self) -> usize {
+        let Some(bounds) = self.bounds() else {
+            return 0;
+        };
+
+        let width = (bounds.max.x - bounds.min.x + 1) as usize;
+        let height = (bounds.max.y - bounds.min.y + 1) as usize;
+        width * height - self.0.len()
+    }
+
+    // Whether no elf is in direction dir from pos, straight ahead or diagonally to either side
+    fn clear(&self, pos: Pos, dir: Direction) -> bool {
+        let ahead = pos + dir.vector();
+        let side = dir.turn_right().vector();
+        [ahead - side, ahead, ahead + side]
+            .iter()
+            .all(|pos| !self.0.contains(pos))
+    }
+
+    // Has each elf propose a move, considering directions from first on, then makes the moves no other elf proposed
+    //
+    // Returns whether any elf moved.
+    fn round(&mut self, first: usize) -> bool {
+        // where each move is to, and the elf proposing it (or None if more than one is)
+        let mut proposals: HashMap<Pos, Option<Pos>> = HashMap::new();
+
+        for &elf in &self.0 {
+            let clear = PRIORITY.map(|dir| self.clear(elf, dir));
+
+            // with no one around, or no way to go, an elf stays put
+            if clear.iter().all(|c| *c) || !clear.iter().any(|c| *c) {
+                continue;
+            }
+
+            let idx = (first..first + PRIORITY.len())
+                .map(|i| i % PRIORITY.len())
+                .find(|i| clear[*i])
+                .expect("Some direction is clear");
+
+            proposals
+                .entry(elf + PRIORITY[idx].vector())
+                .and_modify(|from| *from = None)
+                .or_insert(Some(elf));
+        }
+
+        let mut moved = false;
+
+        for (to, from) in proposals {
+            if let Some(from) = from {
+                self.0.remove(&from);
+                self.0.insert(to);
+                moved = true;
+            }
+        }
+
+        moved
+    }
+}
+
+impl fmt::Debug for Elves {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let ch = if self.0.contains(&Pos::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{ch}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// The order the elves consider moving in on the first round; each round starts one further along
+const PRIORITY: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+pub fn parse(input: &str) -> Result<Elves, ParseError> {
+    let map = Grid::parse(input, "an elf (#) or empty ground (.)", |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let elves = map
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((x, y), _)| Pos::new(x as i32, y as i32))
+        .collect();
+
+    Ok(Elves(elves))
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut elves = common::phase("parse", || parse(input))?;
+    common::trace!("initial state\n{elves:?}");
+
+    let mut round = 0;
+
+    loop {
+        round += 1;
+        let moved = elves.round((round - 1) % PRIORITY.len());
+
+        common::step(|| Step::new(format!("round {round}"), &elves))?;
+
+        match part {
+            Part::One if round == 10 => {
+                common::trace!("after round 10\n{elves:?}");
+                return Ok(elves.empty_ground().into());
+            }
+            Part::Two if !moved => return Ok(round.into()),
+            _ => (),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,5 @@
+use anyhow::Error;
+
+fn main() -> Result<(), Error> {
+    common::run(23, day23::part1, day23::part2)
+}
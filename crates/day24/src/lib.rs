@@ -0,0 +1,235 @@
+use anyhow::{bail, Error};
+use common::{Answer, ParseError, Part, Step};
+use geom::Direction;
+use grid::Grid;
+use std::collections::HashSet;
+use std::fmt;
+
+type Pos = (usize, usize);
+
+fn gcd(a: usize, b: usize) -> usize {
+    if a == 0 {
+        b
+    } else {
+        gcd(b % a, a)
+    }
+}
+
+// Cell
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Wall,
+    Open,
+    Blizzard(Direction), // where it starts out, at minute 0
+}
+
+// Valley
+
+// The map, walls and all, with the blizzards where they start out
+//
+// Blizzards wrap around within the walls, so after width minutes every horizontal one is back where it started, and
+// after height minutes every vertical one; rather than moving them, where they are is worked out from the minute.
+#[derive(Debug)]
+pub struct Valley {
+    pub cells: Grid<Cell>,
+    pub start: Pos, // the gap in the top wall
+    pub goal: Pos,  // the gap in the bottom wall
+}
+
+impl Valley {
+    // Of the space inside the walls
+    fn inner_width(&self) -> usize {
+        self.cells.width() - 2
+    }
+
+    fn inner_height(&self) -> usize {
+        self.cells.height() - 2
+    }
+
+    // After this many minutes, every blizzard is back where it started
+    fn period(&self) -> usize {
+        let (w, h) = (self.inner_width(), self.inner_height());
+        w * h / gcd(w, h)
+    }
+
+    // Whether a blizzard heading in dir is at (x, y) (inside the walls) at minute time
+    fn blizzard_at(&self, (x, y): Pos, dir: Direction, time: usize) -> bool {
+        let (w, h) = (self.inner_width(), self.inner_height());
+        let (ix, iy) = (x - 1, y - 1);
+
+        // where it would have started out
+        let (sx, sy) = match dir {
+            Direction::Right => ((ix + w - time % w) % w, iy),
+            Direction::Left => ((ix + time) % w, iy),
+            Direction::Down => (ix, (iy + h - time % h) % h),
+            Direction::Up => (ix, (iy + time) % h),
+        };
+
+        self.cells[(sx + 1, sy + 1)] == Cell::Blizzard(dir)
+    }
+
+    fn blizzards_at(&self, pos: Pos, time: usize) -> impl Iterator<Item = Direction> + '_ {
+        let inside = self.cells[pos] != Cell::Wall && pos != self.start && pos != self.goal;
+        Direction::ALL
+            .into_iter()
+            .filter(move |dir| inside && self.blizzard_at(pos, *dir, time))
+    }
+
+    fn is_free(&self, pos: Pos, time: usize) -> bool {
+        self.cells[pos] != Cell::Wall && self.blizzards_at(pos, time).next().is_none()
+    }
+
+    // The minute of the earliest arrival at to, leaving from at minute start_time
+    //
+    // Each minute's frontier holds every position that can be reached by then. A position at a minute the blizzards
+    // have been in before (a multiple of the period ago) is no better than it was then, so it isn't searched again,
+    // and the frontier runs out if to can't be reached at all.
+    fn shortest_trip(&self, from: Pos, to: Pos, start_time: usize) -> Result<usize, Error> {
+        let period = self.period();
+
+        let mut frontier = vec![from];
+        let mut seen = HashSet::new();
+        seen.insert((from, start_time % period));
+
+        let mut time = start_time;
+
+        while !frontier.is_empty() {
+            common::step(|| {
+                let minute = Minute {
+                    valley: self,
+                    time,
+                    frontier: &frontier,
+                };
+                Step::new(format!("minute {time}"), &minute)
+            })?;
+
+            if frontier.contains(&to) {
+                return Ok(time);
+            }
+
+            time += 1;
+            let mut next = Vec::new();
+
+            for &(x, y) in &frontier {
+                let moves = std::iter::once((x, y)).chain(self.cells.neighbors4(x, y));
+                for pos in moves {
+                    if self.is_free(pos, time) && seen.insert((pos, time % period)) {
+                        next.push(pos);
+                    }
+                }
+            }
+
+            frontier = next;
+        }
+
+        bail!("No way from {from:?} to {to:?} past the blizzards");
+    }
+}
+
+// Draws the valley at a minute, with each position the expedition could be in as E
+//
+// As in the puzzle, a spot with more than one blizzard in it shows how many.
+struct Minute<'a> {
+    valley: &'a Valley,
+    time: usize,
+    frontier: &'a [Pos],
+}
+
+impl fmt::Debug for Minute<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells = &self.valley.cells;
+
+        for y in 0..cells.height() {
+            for x in 0..cells.width() {
+                let blizzards = self
+                    .valley
+                    .blizzards_at((x, y), self.time)
+                    .collect::<Vec<_>>();
+
+                let ch = match blizzards[..] {
+                    _ if cells[(x, y)] == Cell::Wall => '#',
+                    _ if self.frontier.contains(&(x, y)) => 'E',
+                    [] => '.',
+                    [Direction::Up] => '^',
+                    [Direction::Right] => '>',
+                    [Direction::Down] => 'v',
+                    [Direction::Left] => '<',
+                    _ => char::from_digit(blizzards.len() as u32, 10).unwrap_or('*'),
+                };
+                write!(f, "{ch}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Valley, ParseError> {
+    let cells = Grid::parse(input, "#, ., <, >, ^ or v", |ch| match ch {
+        '#' => Some(Cell::Wall),
+        '.' => Some(Cell::Open),
+        '^' => Some(Cell::Blizzard(Direction::Up)),
+        '>' => Some(Cell::Blizzard(Direction::Right)),
+        'v' => Some(Cell::Blizzard(Direction::Down)),
+        '<' => Some(Cell::Blizzard(Direction::Left)),
+        _ => None,
+    })?;
+
+    let (width, height) = (cells.width(), cells.height());
+    if width < 3 || height < 3 {
+        return Err(ParseError::at(input, input, "a valley inside walls"));
+    }
+
+    let lines = input.lines().collect::<Vec<_>>();
+
+    // a wall all the way around, but for one gap at the top and one at the bottom
+    let gap = |y: usize| {
+        let mut gaps = (0..width).filter(|x| cells[(*x, y)] != Cell::Wall);
+
+        match (gaps.next(), gaps.next()) {
+            (Some(x), None) if x > 0 && x < width - 1 && cells[(x, y)] == Cell::Open => Ok((x, y)),
+            _ => Err(ParseError::at(input, lines[y], "a wall with one gap in it")),
+        }
+    };
+
+    let start = gap(0)?;
+    let goal = gap(height - 1)?;
+
+    for y in 1..height - 1 {
+        let walls = (0..width).filter(|x| cells[(*x, y)] == Cell::Wall);
+        if !walls.eq([0, width - 1]) {
+            let expected = "a wall at each end and none between";
+            return Err(ParseError::at(input, lines[y], expected));
+        }
+    }
+
+    Ok(Valley { cells, start, goal })
+}
+
+fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let valley = common::phase("parse", || parse(input))?;
+    let (start, goal) = (valley.start, valley.goal);
+
+    let there = valley.shortest_trip(start, goal, 0)?;
+    if part == Part::One {
+        return Ok(there.into());
+    }
+
+    // back for the snacks, then there again
+    let back = valley.shortest_trip(goal, start, there)?;
+    let there_again = valley.shortest_trip(start, goal, back)?;
+    common::detail("arrivals", [there, back, there_again]);
+
+    Ok(there_again.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, Error> {
+    solve(input, Part::Two)
+}
@@ -0,0 +1,5 @@
+use anyhow::Error;
+
+fn main() -> Result<(), Error> {
+    common::run(24, day24::part1, day24::part2)
+}
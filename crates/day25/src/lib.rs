@@ -0,0 +1,283 @@
+use anyhow::{anyhow, bail, Error};
+use common::{parse_token, Answer, ParseError};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// Snafu
+
+// A number in SNAFU, which is balanced base 5: each digit is one of =, -, 0, 1 or 2, worth -2 to 2
+//
+// Arithmetic is done digit by digit, so numbers can be any size.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Snafu {
+    digits: Vec<i8>, // least significant first, with no zeros at the most significant end (so zero has no digits)
+}
+
+impl Snafu {
+    // Carries columns of any size over until each is a digit
+    fn from_columns(columns: impl IntoIterator<Item = i128>) -> Self {
+        let mut digits = Vec::new();
+        let mut carry = 0;
+        let mut columns = columns.into_iter();
+
+        loop {
+            let value = match columns.next() {
+                Some(column) => column + carry,
+                None if carry != 0 => carry,
+                None => break,
+            };
+
+            // value = 5 * carry + digit, with the digit from -2 to 2
+            let rem = value.rem_euclid(5);
+            let (digit, next_carry) = if rem > 2 {
+                (rem - 5, value.div_euclid(5) + 1)
+            } else {
+                (rem, value.div_euclid(5))
+            };
+
+            digits.push(digit as i8);
+            carry = next_carry;
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        Self { digits }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    // The sign of the most significant digit is the sign of the number
+    pub fn signum(&self) -> i8 {
+        self.digits.last().map_or(0, |digit| digit.signum())
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            bail!("Empty SNAFU number");
+        }
+
+        let columns = s
+            .chars()
+            .rev()
+            .map(|ch| match ch {
+                '=' => Ok(-2),
+                '-' => Ok(-1),
+                '0' => Ok(0),
+                '1' => Ok(1),
+                '2' => Ok(2),
+                _ => Err(anyhow!("Invalid SNAFU digit {ch:?}")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_columns(columns))
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+
+        for digit in self.digits.iter().rev() {
+            let ch = match digit {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                _ => '2',
+            };
+            write!(f, "{ch}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Conversions
+
+macro_rules! snafu_int_conversions {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Snafu {
+                fn from(value: $ty) -> Self {
+                    Self::from_columns([value as i128])
+                }
+            }
+
+            impl TryFrom<&Snafu> for $ty {
+                type Error = Error;
+
+                fn try_from(value: &Snafu) -> Result<Self, Self::Error> {
+                    value
+                        .digits
+                        .iter()
+                        .rev()
+                        .try_fold(<$ty>::from(0i8), |acc, digit| {
+                            // a digit against the sign so far is borrowed from acc first, as 5 * acc
+                            // alone can overflow when the result wouldn't (like for the type's MIN)
+                            let (acc, digit) = match (acc.signum(), digit.signum()) {
+                                (-1, 1) => (acc + 1, *digit as $ty - 5),
+                                (1, -1) => (acc - 1, *digit as $ty + 5),
+                                _ => (acc, *digit as $ty),
+                            };
+                            acc.checked_mul(5)?.checked_add(digit)
+                        })
+                        .ok_or_else(|| anyhow!("{value} doesn't fit in {}", stringify!($ty)))
+                }
+            }
+        )*
+    };
+}
+
+snafu_int_conversions!(i64, i128);
+
+impl From<Snafu> for Answer {
+    fn from(value: Snafu) -> Self {
+        value.to_string().into()
+    }
+}
+
+// Arithmetic
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Snafu {
+        let len = self.digits.len().max(rhs.digits.len());
+        let column = |snafu: &Snafu, i| *snafu.digits.get(i).unwrap_or(&0) as i128;
+        Snafu::from_columns((0..len).map(|i| column(self, i) + column(rhs, i)))
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Snafu {
+        &self + &rhs
+    }
+}
+
+impl AddAssign<&Snafu> for Snafu {
+    fn add_assign(&mut self, rhs: &Snafu) {
+        *self = &*self + rhs;
+    }
+}
+
+impl Neg for &Snafu {
+    type Output = Snafu;
+
+    // Balanced digits make this just a matter of flipping each one
+    fn neg(self) -> Snafu {
+        Snafu {
+            digits: self.digits.iter().map(|digit| -digit).collect(),
+        }
+    }
+}
+
+impl Neg for Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Snafu {
+        -&self
+    }
+}
+
+impl Sub for &Snafu {
+    type Output = Snafu;
+
+    fn sub(self, rhs: Self) -> Snafu {
+        self + &-rhs
+    }
+}
+
+impl Sub for Snafu {
+    type Output = Snafu;
+
+    fn sub(self, rhs: Self) -> Snafu {
+        &self - &rhs
+    }
+}
+
+impl Mul for &Snafu {
+    type Output = Snafu;
+
+    // Long multiplication, carrying once all the columns are summed
+    fn mul(self, rhs: Self) -> Snafu {
+        if self.is_zero() || rhs.is_zero() {
+            return Snafu::default();
+        }
+
+        let mut columns = vec![0; self.digits.len() + rhs.digits.len()];
+        for (i, a) in self.digits.iter().enumerate() {
+            for (j, b) in rhs.digits.iter().enumerate() {
+                columns[i + j] += (*a * *b) as i128;
+            }
+        }
+
+        Snafu::from_columns(columns)
+    }
+}
+
+impl Mul for Snafu {
+    type Output = Snafu;
+
+    fn mul(self, rhs: Self) -> Snafu {
+        &self * &rhs
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |mut sum, snafu| {
+            sum += snafu;
+            sum
+        })
+    }
+}
+
+impl Ord for Snafu {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self - other).signum().cmp(&0)
+    }
+}
+
+impl PartialOrd for Snafu {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Snafu>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_token(input, line, "a SNAFU number (digits 2, 1, 0, - and =)"))
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<Answer, Error> {
+    let numbers = common::phase("parse", || parse(input))?;
+    let sum = numbers.iter().sum::<Snafu>();
+
+    if let Ok(decimal) = i64::try_from(&sum) {
+        common::detail("decimal", decimal);
+    }
+
+    Ok(sum.into())
+}
+
+// The last day's second star is for having all the others, so there's nothing to solve
+pub fn part2(_input: &str) -> Result<Answer, Error> {
+    Ok(Answer::NotApplicable)
+}
@@ -0,0 +1,5 @@
+use anyhow::Error;
+
+fn main() -> Result<(), Error> {
+    common::run(25, day25::part1, day25::part2)
+}
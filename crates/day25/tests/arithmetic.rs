@@ -0,0 +1,85 @@
+use day25::Snafu;
+use proptest::prelude::*;
+
+fn snafu(value: i128) -> Snafu {
+    Snafu::from(value)
+}
+
+#[test]
+fn examples() {
+    let examples: &[(i64, &str)] = &[
+        (0, "0"),
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (8, "2="),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+        (-3, "-2"),
+    ];
+
+    for (decimal, text) in examples {
+        assert_eq!(Snafu::from(*decimal).to_string(), *text);
+        assert_eq!(
+            i64::try_from(&text.parse::<Snafu>().unwrap()).unwrap(),
+            *decimal
+        );
+    }
+
+    // zeros at the front don't count
+    assert_eq!("002=".parse::<Snafu>().unwrap(), Snafu::from(8i64));
+    assert_eq!("00".parse::<Snafu>().unwrap().to_string(), "0");
+}
+
+#[test]
+fn invalid_digits() {
+    for text in ["", "3", "1=-0-2 ", "1_0", "+1"] {
+        assert!(text.parse::<Snafu>().is_err(), "{text:?}");
+    }
+}
+
+#[test]
+fn extremes() {
+    for value in [i128::MIN, i128::MAX, i64::MIN as i128, i64::MAX as i128] {
+        let text = snafu(value).to_string();
+        assert_eq!(
+            i128::try_from(&text.parse::<Snafu>().unwrap()).unwrap(),
+            value
+        );
+    }
+
+    assert!(i64::try_from(&snafu(i64::MAX as i128 + 1)).is_err());
+    assert!(i128::try_from(&(snafu(i128::MAX) + snafu(1))).is_err());
+}
+
+proptest! {
+    #[test]
+    fn round_trip(value in any::<i128>()) {
+        let text = snafu(value).to_string();
+        prop_assert_eq!(i128::try_from(&text.parse::<Snafu>().unwrap()).unwrap(), value);
+    }
+
+    #[test]
+    fn arithmetic_matches_integers(a in any::<i64>(), b in any::<i64>()) {
+        let (a, b) = (a as i128, b as i128);
+        let (sa, sb) = (snafu(a), snafu(b));
+
+        prop_assert_eq!(&sa + &sb, snafu(a + b));
+        prop_assert_eq!(&sa - &sb, snafu(a - b));
+        prop_assert_eq!(-&sa, snafu(-a));
+        prop_assert_eq!(&sa * &sb, snafu(a * b));
+        prop_assert_eq!(sa.cmp(&sb), a.cmp(&b));
+    }
+
+    #[test]
+    fn sums_match_integers(values in prop::collection::vec(any::<i64>(), 0..20)) {
+        let sum = values.iter().map(|v| Snafu::from(*v)).collect::<Vec<_>>().iter().sum::<Snafu>();
+        prop_assert_eq!(sum, snafu(values.iter().map(|v| *v as i128).sum()));
+    }
+}
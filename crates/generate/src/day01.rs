@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size elves, each carrying a few items of food
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let num_items = rng.random_range(1..=15);
+            (0..num_items)
+                .map(|_| format!("{}\n", rng.random_range(1000..=70000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
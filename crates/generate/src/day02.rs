@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size rounds of rock paper scissors
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let theirs = ['A', 'B', 'C'][rng.random_range(0..3)];
+            let ours = ['X', 'Y', 'Z'][rng.random_range(0..3)];
+            format!("{theirs} {ours}\n")
+        })
+        .collect()
+}
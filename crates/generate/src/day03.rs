@@ -0,0 +1,49 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// size rucksacks (rounded up to a whole group of three)
+//
+// Each rucksack has exactly one item in both compartments, and each group exactly one badge.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size.div_ceil(3) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let (badge, rest) = items.split_first().unwrap();
+
+        // each elf avoids one third of the other items, so only the badge is common to all three
+        let thirds = rest.chunks(rest.len() / 3).collect::<Vec<_>>();
+
+        for elf in 0..3 {
+            let mut allowed = (0..3)
+                .filter(|third| *third != elf)
+                .flat_map(|third| thirds[third].iter().copied())
+                .collect::<Vec<_>>();
+            allowed.shuffle(rng);
+
+            let (left_items, right_items) = allowed.split_at(allowed.len() / 2);
+            out.push_str(&rucksack(rng, *badge, left_items, right_items));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn rucksack(rng: &mut StdRng, badge: u8, left_items: &[u8], right_items: &[u8]) -> String {
+    let half_len = rng.random_range(4..=24);
+    let shared = *left_items.choose(rng).unwrap();
+
+    let mut left = vec![shared, badge];
+    let mut right = vec![shared];
+    left.extend((left.len()..half_len).map(|_| *left_items.choose(rng).unwrap()));
+    right.extend((right.len()..half_len).map(|_| *right_items.choose(rng).unwrap()));
+    left.shuffle(rng);
+    right.shuffle(rng);
+
+    left.into_iter().chain(right).map(char::from).collect()
+}
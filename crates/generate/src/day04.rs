@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size pairs of section assignments
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut assignment = || {
+        let start = rng.random_range(1..=99);
+        let end = rng.random_range(start..=99);
+        format!("{start}-{end}")
+    };
+
+    (0..size)
+        .map(|_| format!("{},{}\n", assignment(), assignment()))
+        .collect()
+}
@@ -0,0 +1,57 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const NUM_STACKS: usize = 9;
+
+// size moves, each taking crates from a stack that has them
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut stacks = (0..NUM_STACKS)
+        .map(|_| {
+            let height = rng.random_range(1..=8);
+            (0..height)
+                .map(|_| char::from(rng.random_range(b'A'..=b'Z')))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+
+    let max_height = stacks.iter().map(String::len).max().unwrap();
+    for row in (0..max_height).rev() {
+        let cells = stacks
+            .iter()
+            .map(|stack| match stack.as_bytes().get(row) {
+                Some(ch) => format!("[{}]", char::from(*ch)),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&cells.join(" "));
+        out.push('\n');
+    }
+
+    let numbers = (1..=NUM_STACKS)
+        .map(|n| format!(" {n} "))
+        .collect::<Vec<_>>();
+    out.push_str(&numbers.join(" "));
+    out.push_str("\n\n");
+
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.random_range(0..NUM_STACKS);
+            if !stacks[from].is_empty() {
+                break from;
+            }
+        };
+        let to = rng.random_range(0..NUM_STACKS);
+        let count = rng.random_range(1..=stacks[from].len());
+
+        // either crane moves the same number of crates, so the stack sizes stay valid for both parts
+        let remaining = stacks[from].len() - count;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].push_str(&moved);
+
+        out.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+    }
+
+    out
+}
@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+// A datastream of about size characters, with its markers somewhere in the middle
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // any four characters drawn from three letters repeat one, so there's no marker before the real one
+    let prefix_len = rng.random_range(0..=size / 2);
+    let mut stream = (0..prefix_len)
+        .map(|_| *b"abc".choose(rng).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut marker = (b'a'..=b'z').collect::<Vec<_>>();
+    marker.shuffle(rng);
+    stream.extend(&marker[..14]);
+
+    stream.extend((stream.len()..size).map(|_| rng.random_range(b'a'..=b'z')));
+
+    let mut out = stream.into_iter().map(char::from).collect::<String>();
+    out.push('\n');
+    out
+}
@@ -0,0 +1,74 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+const NAMES: &[&str] = &["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"];
+const EXTENSIONS: &[&str] = &["", ".txt", ".dat", ".lst", ".log"];
+
+#[derive(Default)]
+struct Dir {
+    files: Vec<(String, u32)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+impl Dir {
+    fn add_file(&mut self, rng: &mut StdRng) {
+        let unused = self.unused_names();
+
+        // one name is always left free, so there's somewhere to go when the rest are taken
+        if !self.dirs.is_empty() && (unused.is_empty() || rng.random_bool(0.6)) {
+            let idx = rng.random_range(0..self.dirs.len());
+            self.dirs[idx].1.add_file(rng);
+        } else if unused.len() == 1 || rng.random_bool(0.2) {
+            let mut dir = Dir::default();
+            dir.add_file(rng);
+            self.dirs
+                .push((unused.choose(rng).unwrap().to_string(), dir));
+        } else {
+            let name = unused.choose(rng).unwrap().to_string() + EXTENSIONS.choose(rng).unwrap();
+            self.files.push((name, rng.random_range(1000..=300_000)));
+        }
+    }
+
+    // Names are shared between files and directories, ignoring extensions
+    fn unused_names(&self) -> Vec<&'static str> {
+        NAMES
+            .iter()
+            .copied()
+            .filter(|name| {
+                let is_file = |(file, _): &(String, u32)| file.split('.').next() == Some(*name);
+                !self.files.iter().any(is_file) && !self.dirs.iter().any(|(dir, _)| dir == name)
+            })
+            .collect()
+    }
+
+    fn write_session(&self, out: &mut String) {
+        out.push_str("$ ls\n");
+
+        for (name, _) in &self.dirs {
+            out.push_str(&format!("dir {name}\n"));
+        }
+
+        for (name, size) in &self.files {
+            out.push_str(&format!("{size} {name}\n"));
+        }
+
+        for (name, dir) in &self.dirs {
+            out.push_str(&format!("$ cd {name}\n"));
+            dir.write_session(out);
+            out.push_str("$ cd ..\n");
+        }
+    }
+}
+
+// A terminal session exploring a filesystem of size files
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut root = Dir::default();
+    for _ in 0..size {
+        root.add_file(rng);
+    }
+
+    let mut out = "$ cd /\n".to_owned();
+    root.write_session(&mut out);
+    out
+}
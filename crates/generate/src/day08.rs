@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A size by size grid of tree heights
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = (0..size)
+                .map(|_| char::from(b'0' + rng.random_range(0..=9)))
+                .collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+// size motions of the rope's head
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = ["L", "R", "U", "D"].choose(rng).unwrap();
+            format!("{dir} {}\n", rng.random_range(1..=20))
+        })
+        .collect()
+}
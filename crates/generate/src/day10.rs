@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size instructions, keeping the sprite somewhere near the screen
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut x = 1;
+    let mut out = String::new();
+
+    for _ in 0..size {
+        if rng.random_bool(0.3) {
+            out.push_str("noop\n");
+            continue;
+        }
+
+        let value = loop {
+            let value = rng.random_range(-20..=20);
+            if value != 0 && (-5..=45).contains(&(x + value)) {
+                break value;
+            }
+        };
+
+        x += value;
+        out.push_str(&format!("addx {value}\n"));
+    }
+
+    out
+}
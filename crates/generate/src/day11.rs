@@ -0,0 +1,54 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// Distinct primes keep the product of the divisors, and its square, within a u64
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+// A handful of monkeys holding size items between them
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let num_monkeys = rng.random_range(2..=DIVISORS.len());
+
+    let mut divisors = DIVISORS;
+    divisors.shuffle(rng);
+
+    let mut items = vec![Vec::new(); num_monkeys];
+    for _ in 0..size {
+        items[rng.random_range(0..num_monkeys)].push(rng.random_range(50..=99).to_string());
+    }
+
+    let mut monkeys = Vec::new();
+
+    for (idx, divisor) in divisors.iter().take(num_monkeys).enumerate() {
+        let mut other_monkey = || loop {
+            let other = rng.random_range(0..num_monkeys);
+            if other != idx {
+                break other;
+            }
+        };
+        let (if_true, if_false) = (other_monkey(), other_monkey());
+
+        let operation = match rng.random_range(0..5) {
+            0 => "old * old".to_owned(),
+            1 | 2 => format!("old * {}", rng.random_range(2..=19)),
+            _ => format!("old + {}", rng.random_range(1..=8)),
+        };
+
+        // the parser wants at least one starting item
+        if items[idx].is_empty() {
+            items[idx].push(rng.random_range(50..=99).to_string());
+        }
+
+        monkeys.push(format!(
+            "Monkey {idx}:\n  \
+             Starting items: {}\n  \
+             Operation: new = {operation}\n  \
+             Test: divisible by {divisor}\n    \
+             If true: throw to monkey {if_true}\n    \
+             If false: throw to monkey {if_false}\n",
+            items[idx].join(", ")
+        ));
+    }
+
+    monkeys.join("\n")
+}
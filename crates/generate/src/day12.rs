@@ -0,0 +1,37 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A heightmap size wide, rising from S in the top left corner to E in the bottom right
+//
+// The top row and right column climb at most one step at a time, so there's always a path.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(20);
+    let height = (size / 2).max(8);
+    let max_dist = width + height - 2;
+
+    let mut out = String::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            // E is one higher than z, like the parser has it
+            let base = (x + y) * 26 / max_dist;
+
+            let ch = if (x, y) == (0, 0) {
+                'S'
+            } else if (x, y) == (width - 1, height - 1) {
+                'E'
+            } else if y == 0 || x == width - 1 {
+                char::from(b'a' + base as u8)
+            } else {
+                let noise = rng.random_range(0..=3);
+                char::from(b'a' + base.saturating_sub(noise) as u8)
+            };
+
+            out.push(ch);
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,26 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size pairs of packets
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn packet(rng: &mut StdRng, depth: u32) -> String {
+    let len = rng.random_range(0..=5);
+
+    let items = (0..len)
+        .map(|_| {
+            if depth < 4 && rng.random_bool(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.random_range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", items.join(","))
+}
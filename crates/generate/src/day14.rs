@@ -0,0 +1,28 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size paths of rock, below and to either side of where the sand comes in at 500,0
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let spread = 10 + size as i32;
+
+    (0..size)
+        .map(|_| {
+            let mut x = rng.random_range(500 - spread..=500 + spread);
+            let mut y = rng.random_range(2..=10 + size as i32 / 2);
+            let mut points = vec![format!("{x},{y}")];
+
+            for segment in 0..rng.random_range(1..=5) {
+                // alternate between horizontal and vertical lines, staying below the sand source
+                let len = rng.random_range(1..=8);
+                if segment % 2 == 0 {
+                    x += if rng.random_bool(0.5) { len } else { -len };
+                } else {
+                    y = (y + if rng.random_bool(0.5) { len } else { -len }).max(2);
+                }
+                points.push(format!("{x},{y}"));
+            }
+
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
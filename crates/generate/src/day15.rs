@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Coordinates span about what the real input's do, to go with the default row and search area
+const EXTENT: i32 = 4_000_000;
+
+// size sensors and their beacons, with ranges that shrink as there are more of them
+//
+// Beacons aren't checked against other sensors, and nothing guarantees part 2 a single gap.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let max_range = (EXTENT as f64 / (size as f64).sqrt()) as i32;
+
+    (0..size)
+        .map(|_| {
+            let (sx, sy) = (rng.random_range(0..=EXTENT), rng.random_range(0..=EXTENT));
+
+            let range = rng.random_range(1..=max_range);
+            let dx = rng.random_range(-range..=range);
+            let dy = (range - dx.abs()) * if rng.random_bool(0.5) { 1 } else { -1 };
+            let (bx, by) = (sx + dx, sy + dy);
+
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// size valves (at least two, at most 26 * 26), starting at AA
+//
+// Tunnels always join the valves into one network.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut names = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", char::from(a), char::from(b))))
+        .filter(|name| name != "AA")
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    names.truncate(size.clamp(2, 26 * 26) - 1);
+    names.insert(0, "AA".to_owned());
+
+    // a random spanning tree to keep everything reachable, then a few more tunnels
+    let mut tunnels = vec![Vec::new(); names.len()];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+
+    for valve in 1..names.len() {
+        connect(valve, rng.random_range(0..valve));
+    }
+
+    for _ in 0..names.len() / 2 {
+        connect(
+            rng.random_range(0..names.len()),
+            rng.random_range(0..names.len()),
+        );
+    }
+
+    let mut order = (0..names.len()).collect::<Vec<_>>();
+    order.shuffle(rng);
+
+    order
+        .into_iter()
+        .map(|valve| {
+            // like the real input (15 of its 58 valves have a flow rate), the start valve and about three quarters of the
+            // others are stuck shut
+            let flow_rate = if valve == 0 || rng.random_bool(0.75) {
+                0
+            } else {
+                rng.random_range(1..=25)
+            };
+
+            let leads = tunnels[valve]
+                .iter()
+                .map(|other| names[*other].as_str())
+                .collect::<Vec<_>>();
+
+            let (tunnels, lead, valves) = if leads.len() == 1 {
+                ("tunnel", "leads", "valve")
+            } else {
+                ("tunnels", "lead", "valves")
+            };
+
+            format!(
+                "Valve {} has flow rate={flow_rate}; {tunnels} {lead} to {valves} {}\n",
+                names[valve],
+                leads.join(", ")
+            )
+        })
+        .collect()
+}
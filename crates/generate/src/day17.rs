@@ -0,0 +1,11 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A pattern of size jets
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut out = (0..size)
+        .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+        .collect::<String>();
+    out.push('\n');
+    out
+}
@@ -0,0 +1,42 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+// A lumpy cloud of size cubes, grown outwards from one so it's mostly in one piece
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut cubes = vec![(0i32, 0i32, 0i32)];
+    let mut seen = cubes.iter().copied().collect::<HashSet<_>>();
+
+    while cubes.len() < size {
+        let (x, y, z) = *cubes.choose(rng).unwrap();
+        let (dx, dy, dz) = *[
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .choose(rng)
+        .unwrap();
+
+        // an occasional jump leaves pockets of air inside
+        let step = if rng.random_bool(0.1) { 2 } else { 1 };
+        let cube = (x + dx * step, y + dy * step, z + dz * step);
+
+        if seen.insert(cube) {
+            cubes.push(cube);
+        }
+    }
+
+    // the real input keeps to small non-negative coordinates
+    let min = cubes.iter().fold((0, 0, 0), |(mx, my, mz), (x, y, z)| {
+        (mx.min(*x), my.min(*y), mz.min(*z))
+    });
+
+    cubes
+        .iter()
+        .map(|(x, y, z)| format!("{},{},{}\n", x - min.0, y - min.1, z - min.2))
+        .collect()
+}
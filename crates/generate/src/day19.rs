@@ -0,0 +1,20 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A blueprint for every ten of size, with costs in the same ranges as the puzzle's
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (1..=(size / 10).max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                rng.random_range(2..=4),
+                rng.random_range(2..=4),
+                rng.random_range(2..=4),
+                rng.random_range(5..=20),
+                rng.random_range(2..=4),
+                rng.random_range(5..=20),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,24 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size numbers to mix, with exactly one zero among them
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let zero_idx = rng.random_range(0..size);
+
+    (0..size)
+        .map(|idx| {
+            let number = if idx == zero_idx {
+                0
+            } else {
+                loop {
+                    let number = rng.random_range(-10_000..=10_000);
+                    if number != 0 {
+                        break number;
+                    }
+                }
+            };
+
+            format!("{number}\n")
+        })
+        .collect()
+}
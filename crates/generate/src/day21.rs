@@ -0,0 +1,121 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+use std::collections::HashSet;
+
+// Past this, operations that grow the numbers are skipped so nothing overflows
+const MAX_VALUE: u64 = 1 << 40;
+
+enum Job {
+    Number(u64),
+    Op(usize, char, usize),
+}
+
+struct Tree<'a> {
+    rng: &'a mut StdRng,
+    jobs: Vec<Job>,
+}
+
+impl Tree<'_> {
+    // Adds monkeys (about budget of them) that together yell value, returning the top one
+    fn add(&mut self, value: u64, budget: usize) -> usize {
+        let rng = &mut *self.rng;
+
+        let job = if budget < 3 {
+            Job::Number(value)
+        } else {
+            let left_budget = rng.random_range(1..budget - 1);
+            let right_budget = budget - 1 - left_budget;
+
+            let divisor = [2, 3, 4, 5, 7]
+                .iter()
+                .copied()
+                .filter(|d| value > 0 && value.is_multiple_of(*d))
+                .collect::<Vec<_>>()
+                .choose(rng)
+                .copied();
+
+            // every monkey yells at least 1, since working back through a zero is ambiguous
+            let grow = value < MAX_VALUE;
+            let (left, op, right) = match rng.random_range(0..4) {
+                0 if value >= 2 => {
+                    let left = rng.random_range(1..value);
+                    (left, '+', value - left)
+                }
+                2 if divisor.is_some() => {
+                    let divisor = divisor.unwrap();
+                    (value / divisor, '*', divisor)
+                }
+                3 if grow => {
+                    let divisor = rng.random_range(1..=5);
+                    (value * divisor, '/', divisor)
+                }
+                _ if grow => {
+                    let right = rng.random_range(1..=value.max(10));
+                    (value + right, '-', right)
+                }
+                _ => {
+                    let left = rng.random_range(1..value);
+                    (left, '+', value - left)
+                }
+            };
+
+            let left = self.add(left, left_budget);
+            let right = self.add(right, right_budget);
+            Job::Op(left, op, right)
+        };
+
+        self.jobs.push(job);
+        self.jobs.len() - 1
+    }
+}
+
+// A tree of about size monkeys under root
+//
+// Both sides of root yell the same number with humn as generated, so part 2's answer is humn's number.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let value = rng.random_range(1..=1000);
+
+    let mut tree = Tree {
+        rng: &mut *rng,
+        jobs: Vec::new(),
+    };
+    let budget = size.saturating_sub(1).max(2);
+    let left = tree.add(value, budget / 2);
+    let right = tree.add(value, budget - budget / 2);
+    tree.jobs.push(Job::Op(left, '+', right));
+    let jobs = tree.jobs;
+
+    let leaves = (0..jobs.len())
+        .filter(|idx| matches!(jobs[*idx], Job::Number(_)))
+        .collect::<Vec<_>>();
+    let humn = *leaves.choose(rng).unwrap();
+
+    let mut used = HashSet::from(["root".to_owned(), "humn".to_owned()]);
+    let mut names = (0..jobs.len())
+        .map(|_| loop {
+            let name = (0..4)
+                .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+                .collect::<String>();
+            if used.insert(name.clone()) {
+                break name;
+            }
+        })
+        .collect::<Vec<_>>();
+    names[humn] = "humn".to_owned();
+    *names.last_mut().unwrap() = "root".to_owned();
+
+    let mut lines = jobs
+        .iter()
+        .zip(&names)
+        .map(|(job, name)| match job {
+            Job::Number(value) => format!("{name}: {value}\n"),
+            Job::Op(left, op, right) => {
+                format!("{name}: {} {op} {}\n", names[*left], names[*right])
+            }
+        })
+        .collect::<Vec<_>>();
+
+    lines.shuffle(rng);
+    lines.concat()
+}
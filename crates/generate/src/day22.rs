@@ -0,0 +1,81 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// The 11 nets of a cube, one # per face; the generator flips and turns them for the rest
+const NETS: &[&[&str]] = &[
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+// A board folded from one of the nets, with faces a tenth of size across, then a path of size instructions
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let face_size = (size / 10).max(2);
+
+    // pick a net and pick it up some way or other
+    let net = NETS[rng.random_range(0..NETS.len())];
+    let mut faces = net
+        .iter()
+        .map(|row| row.chars().map(|ch| ch == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    if rng.random_bool(0.5) {
+        faces = (0..faces[0].len())
+            .map(|x| faces.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+    if rng.random_bool(0.5) {
+        faces.reverse();
+    }
+    if rng.random_bool(0.5) {
+        faces.iter_mut().for_each(|row| row.reverse());
+    }
+
+    let mut out = String::new();
+    let mut start = true;
+
+    for face_row in &faces {
+        for _ in 0..face_size {
+            let mut line = String::new();
+
+            for &face in face_row {
+                for _ in 0..face_size {
+                    let ch = match face {
+                        false => ' ',
+                        // the walk starts on the first tile of the top row, which has to be open
+                        true if start => '.',
+                        true if rng.random_bool(0.1) => '#',
+                        true => '.',
+                    };
+
+                    start &= !face;
+                    line.push(ch);
+                }
+            }
+
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+
+    out.push('\n');
+
+    for i in 0..size {
+        if i.is_multiple_of(2) {
+            out.push_str(&rng.random_range(1..=2 * face_size).to_string());
+        } else {
+            out.push(if rng.random_bool(0.5) { 'L' } else { 'R' });
+        }
+    }
+
+    out.push('\n');
+    out
+}
@@ -0,0 +1,18 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A square map half of size tiles across, about half of them elves (they take a while to settle, so it's kept small)
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = (size / 2).max(2);
+    let mut out = String::new();
+
+    for _ in 0..side {
+        for _ in 0..side {
+            out.push(if rng.random_bool(0.5) { '#' } else { '.' });
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,34 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A valley size tiles wide and a quarter as tall inside its walls, with the gaps at opposite corners
+//
+// As in the puzzle, no blizzard heads up or down the columns with the gaps in them, so none can block the way out.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(3);
+    let height = (size / 4).max(2);
+    let gap_columns = [0, width - 1];
+
+    let mut out = format!("#.{}\n", "#".repeat(width));
+
+    for _ in 0..height {
+        out.push('#');
+
+        for x in 0..width {
+            let vertical = !gap_columns.contains(&x);
+            let ch = match rng.random_range(0..10) {
+                0 => '<',
+                1 => '>',
+                2 if vertical => '^',
+                3 if vertical => 'v',
+                _ => '.',
+            };
+            out.push(ch);
+        }
+
+        out.push_str("#\n");
+    }
+
+    out.push_str(&format!("{}.#\n", "#".repeat(width)));
+    out
+}
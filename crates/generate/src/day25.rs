@@ -0,0 +1,28 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+fn snafu(mut value: u64) -> String {
+    let mut digits = Vec::new();
+
+    while value > 0 {
+        let (digit, carry) = match value % 5 {
+            3 => ('=', 1),
+            4 => ('-', 1),
+            rem => (char::from(b'0' + rem as u8), 0),
+        };
+        digits.push(digit);
+        value = value / 5 + carry;
+    }
+
+    digits.into_iter().rev().collect()
+}
+
+// size fuel requirements, in SNAFU, of up to 20 digits
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=20);
+            snafu(rng.random_range(1..5u64.pow(digits))) + "\n"
+        })
+        .collect()
+}
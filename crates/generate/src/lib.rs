@@ -0,0 +1,76 @@
+use anyhow::{anyhow, Error};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+// Each generator writes a puzzle input of roughly size elements (lines, items, cubes...; see each
+// module), in exactly the format the day's parser reads
+type GenerateFn = fn(&mut StdRng, usize) -> String;
+
+const GENERATORS: &[(u32, GenerateFn)] = &[
+    (1, day01::generate),
+    (2, day02::generate),
+    (3, day03::generate),
+    (4, day04::generate),
+    (5, day05::generate),
+    (6, day06::generate),
+    (7, day07::generate),
+    (8, day08::generate),
+    (9, day09::generate),
+    (10, day10::generate),
+    (11, day11::generate),
+    (12, day12::generate),
+    (13, day13::generate),
+    (14, day14::generate),
+    (15, day15::generate),
+    (16, day16::generate),
+    (17, day17::generate),
+    (18, day18::generate),
+    (19, day19::generate),
+    (20, day20::generate),
+    (21, day21::generate),
+    (22, day22::generate),
+    (23, day23::generate),
+    (24, day24::generate),
+    (25, day25::generate),
+];
+
+pub fn days() -> impl Iterator<Item = u32> {
+    GENERATORS.iter().map(|(day, _)| *day)
+}
+
+// The same day, seed and size always give the same input
+pub fn generate(day: u32, seed: u64, size: usize) -> Result<String, Error> {
+    let (_, generate) = GENERATORS
+        .iter()
+        .find(|(number, _)| *number == day)
+        .ok_or_else(|| anyhow!("Day {day} has no input generator"))?;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    Ok(generate(&mut rng, size.max(1)))
+}
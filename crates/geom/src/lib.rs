@@ -0,0 +1,256 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// Coord
+
+// Anything integer-like can be a coordinate; Default is taken to be zero
+pub trait Coord:
+    Copy + Default + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Coord for T where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+}
+
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn signum<T: Coord + From<i8>>(v: T) -> T {
+    match v.cmp(&T::default()) {
+        std::cmp::Ordering::Less => T::from(-1),
+        std::cmp::Ordering::Equal => T::from(0),
+        std::cmp::Ordering::Greater => T::from(1),
+    }
+}
+
+// Point, Vector and BoundingBox, for both 2 and 3 dimensions
+
+macro_rules! geom_types {
+    ($point:ident, $vector:ident, $bbox:ident, $($c:ident),+) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $point<T> {
+            $(pub $c: T,)+
+        }
+
+        impl<T> $point<T> {
+            pub const fn new($($c: T),+) -> Self {
+                Self { $($c),+ }
+            }
+        }
+
+        impl<T: Coord> $point<T> {
+            pub fn component_min(self, other: Self) -> Self {
+                Self { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            pub fn component_max(self, other: Self) -> Self {
+                Self { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            pub fn manhattan(self, other: Self) -> T {
+                T::default() $(+ abs_diff(self.$c, other.$c))+
+            }
+
+            pub fn chebyshev(self, other: Self) -> T {
+                T::default() $(.max(abs_diff(self.$c, other.$c)))+
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct $vector<T> {
+            $(pub $c: T,)+
+        }
+
+        impl<T> $vector<T> {
+            pub const fn new($($c: T),+) -> Self {
+                Self { $($c),+ }
+            }
+        }
+
+        impl<T: Coord + From<i8>> $vector<T> {
+            // Each component clamped to -1, 0 or 1
+            pub fn signum(self) -> Self {
+                Self { $($c: signum(self.$c)),+ }
+            }
+        }
+
+        impl<T: Coord> Add<$vector<T>> for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: $vector<T>) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign<$vector<T>> for $point<T> {
+            fn add_assign(&mut self, rhs: $vector<T>) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Coord> Sub<$vector<T>> for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: $vector<T>) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> SubAssign<$vector<T>> for $point<T> {
+            fn sub_assign(&mut self, rhs: $vector<T>) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, rhs: Self) -> $vector<T> {
+                $vector { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Add for $vector<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $vector<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $vector<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $vector<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        // Inclusive at both ends
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct $bbox<T> {
+            pub min: $point<T>,
+            pub max: $point<T>,
+        }
+
+        impl<T: Coord> $bbox<T> {
+            pub fn new(point: $point<T>) -> Self {
+                Self { min: point, max: point }
+            }
+
+            pub fn from_points<I>(points: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = $point<T>>,
+            {
+                let mut points = points.into_iter();
+                let mut bbox = Self::new(points.next()?);
+                points.for_each(|point| bbox.include(point));
+                Some(bbox)
+            }
+
+            pub fn include(&mut self, point: $point<T>) {
+                self.min = self.min.component_min(point);
+                self.max = self.max.component_max(point);
+            }
+
+            pub fn contains(&self, point: $point<T>) -> bool {
+                true $(&& self.min.$c <= point.$c && point.$c <= self.max.$c)+
+            }
+
+            // Pushes every side out by amount
+            pub fn grow(self, amount: T) -> Self {
+                Self {
+                    min: $point { $($c: self.min.$c - amount),+ },
+                    max: $point { $($c: self.max.$c + amount),+ },
+                }
+            }
+        }
+    };
+}
+
+geom_types!(Point2, Vector2, BoundingBox2, x, y);
+geom_types!(Point3, Vector3, BoundingBox3, x, y, z);
+
+impl<T: Coord + From<i8>> Point2<T> {
+    // The points above, right of, below and left of this one
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction::ALL.map(|dir| self + dir.vector())
+    }
+}
+
+impl<T: Coord + From<i8>> Point3<T> {
+    // The points sharing a face with this one
+    pub fn neighbors6(self) -> [Self; 6] {
+        let [zero, one] = [T::from(0), T::from(1)];
+        let x = Vector3::new(one, zero, zero);
+        let y = Vector3::new(zero, one, zero);
+        let z = Vector3::new(zero, zero, one);
+        [self - x, self + x, self - y, self + y, self - z, self + z]
+    }
+}
+
+// Direction
+
+// A direction on a screen or grid, where y grows downwards
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise from up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn vector<T: From<i8>>(self) -> Vector2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        };
+
+        Vector2::new(T::from(x), T::from(y))
+    }
+}
@@ -0,0 +1,350 @@
+use anyhow::{anyhow, ensure, Error};
+use common::image::{Image, Rgb};
+use common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub mod traversal;
+
+// Grid
+
+// A rectangular grid of cells, stored row-major and addressed by (x, y) with y = 0 as the first row
+#[derive(Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize) -> Self
+    where
+        T: Clone + Default,
+    {
+        Self::filled(width, height, T::default())
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self, Error> {
+        ensure!(
+            width > 0 || cells.is_empty(),
+            "Grid with cells has no width"
+        );
+
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        ensure!(
+            width * height == cells.len(),
+            "{} cells do not fill rows of width {width}",
+            cells.len()
+        );
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    // Parses a map with one character per cell and one line per row
+    //
+    // f maps each character to a cell, or None if it isn't one of the expected characters.
+    pub fn parse<F>(input: &str, expected: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let line_width = line.chars().count();
+
+            match width {
+                Some(width) if line_width != width => {
+                    let expected = format!("a line {width} characters wide");
+                    return Err(ParseError::at(input, line, expected));
+                }
+                Some(_) => (),
+                None => width = Some(line_width),
+            }
+
+            for (idx, ch) in line.char_indices() {
+                let cell = f(ch).ok_or_else(|| ParseError::at(input, &line[idx..], expected))?;
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    // Returns false (and leaves the grid alone) if the position is out of bounds
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx % width, idx / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The first position, row by row, whose cell matches
+    pub fn position<F>(&self, mut f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    // Adds a row past the current last one
+    pub fn push_row<I>(&mut self, row: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.cells.len();
+        self.cells.extend(row);
+
+        if self.cells.len() - len != self.width {
+            let row_width = self.cells.len() - len;
+            self.cells.truncate(len);
+            return Err(anyhow!(
+                "Row is {row_width} cells wide, expected {}",
+                self.width
+            ));
+        }
+
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        if self.height == 0 {
+            return None;
+        }
+
+        self.height -= 1;
+        Some(self.cells.split_off(self.height * self.width))
+    }
+
+    // Scans
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks_exact panics on a zero chunk size, and a zero-width grid has no cells anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // Cells from (x, y) heading down and to the right until the edge of the grid
+    pub fn diagonal(&self, x: usize, y: usize) -> impl Iterator<Item = &T> {
+        (0..).map_while(move |i| self.get(x + i, y + i))
+    }
+
+    // Cells from (x, y) heading down and to the left until the edge of the grid
+    pub fn anti_diagonal(&self, x: usize, y: usize) -> impl Iterator<Item = &T> {
+        (0..=x).map_while(move |i| self.get(x - i, y + i))
+    }
+
+    // Neighbors
+
+    // In-bounds positions left, right, above and below (x, y)
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        self.offset_positions(x, y, &OFFSETS)
+    }
+
+    // In-bounds positions surrounding (x, y), including diagonals
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        self.offset_positions(x, y, &OFFSETS)
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    // Rendering
+
+    // Renders with a custom formatter for each cell, one line per row
+    pub fn render<F>(&self, f: F) -> Render<'_, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+    {
+        Render {
+            grid: self,
+            cell_fmt: f,
+            bottom_up: false,
+        }
+    }
+
+    // Draws each cell as a scale by scale square of the color f gives it
+    pub fn to_image<F>(&self, scale: usize, f: F) -> Image
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let scale = scale.max(1);
+        let mut image = Image::new(
+            self.width * scale,
+            self.height * scale,
+            common::image::BLACK,
+        );
+
+        for ((x, y), cell) in self.iter() {
+            image.fill_rect(x * scale, y * scale, scale, scale, f(cell));
+        }
+
+        image
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("Position ({x}, {y}) out of bounds"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("Position ({x}, {y}) out of bounds"))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let render = self.render(|cell, f| write!(f, "{cell:?} "));
+        fmt::Display::fmt(&render, f)
+    }
+}
+
+// Render
+
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell_fmt: F,
+    bottom_up: bool,
+}
+
+impl<'a, T, F> Render<'a, T, F> {
+    // Puts the last row at the top, for grids that grow upwards
+    pub fn bottom_up(self) -> Self {
+        Self {
+            bottom_up: true,
+            ..self
+        }
+    }
+}
+
+impl<'a, T, F> fmt::Display for Render<'a, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut write_row = |row: &[T]| -> fmt::Result {
+            for cell in row {
+                (self.cell_fmt)(cell, f)?;
+            }
+
+            f.write_str("\n")
+        };
+
+        if self.bottom_up {
+            self.grid.rows().rev().try_for_each(&mut write_row)
+        } else {
+            self.grid.rows().try_for_each(&mut write_row)
+        }
+    }
+}
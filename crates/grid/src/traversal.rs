@@ -0,0 +1,65 @@
+use std::iter::once;
+
+// Orders for visiting every position of a width by height grid, one line at a time
+//
+// Each line (a row, a column, a ring of a spiral) ends with a Reset, for scans that carry state along a line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Visit(usize, usize),
+    Reset,
+}
+
+// Rows top to bottom, each left to right
+pub fn rows(width: usize, height: usize) -> impl Iterator<Item = Action> {
+    (0..height).flat_map(move |y| line((0..width).map(move |x| (x, y))))
+}
+
+// Rows top to bottom, each right to left
+pub fn rows_rev(width: usize, height: usize) -> impl Iterator<Item = Action> {
+    (0..height).flat_map(move |y| line((0..width).rev().map(move |x| (x, y))))
+}
+
+// Columns left to right, each top to bottom
+pub fn columns(width: usize, height: usize) -> impl Iterator<Item = Action> {
+    (0..width).flat_map(move |x| line((0..height).map(move |y| (x, y))))
+}
+
+// Columns left to right, each bottom to top
+pub fn columns_rev(width: usize, height: usize) -> impl Iterator<Item = Action> {
+    (0..width).flat_map(move |x| line((0..height).rev().map(move |y| (x, y))))
+}
+
+// Rings from the outside in, each clockwise from its top left corner
+pub fn spiral(width: usize, height: usize) -> impl Iterator<Item = Action> {
+    let num_rings = width.min(height).div_ceil(2);
+    (0..num_rings).flat_map(move |ring| line(ring_positions(width, height, ring)))
+}
+
+fn ring_positions(
+    width: usize,
+    height: usize,
+    ring: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    let (left, top) = (ring, ring);
+    let (right, bottom) = (width - 1 - ring, height - 1 - ring);
+
+    // a ring one row or column thick has no way back along the other side
+    let bottom_row = if bottom > top { left..right } else { 0..0 };
+    let left_column = if right > left { top + 1..bottom } else { 0..0 };
+
+    let top_row = (left..=right).map(move |x| (x, top));
+    let right_column = (top + 1..=bottom).map(move |y| (right, y));
+    let bottom_row = bottom_row.rev().map(move |x| (x, bottom));
+    let left_column = left_column.rev().map(move |y| (left, y));
+
+    top_row
+        .chain(right_column)
+        .chain(bottom_row)
+        .chain(left_column)
+}
+
+fn line(positions: impl Iterator<Item = (usize, usize)>) -> impl Iterator<Item = Action> {
+    positions
+        .map(|(x, y)| Action::Visit(x, y))
+        .chain(once(Action::Reset))
+}
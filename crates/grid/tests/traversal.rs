@@ -0,0 +1,89 @@
+use grid::traversal::{self, Action};
+use std::collections::HashSet;
+
+type Order = fn(usize, usize) -> Box<dyn Iterator<Item = Action>>;
+
+// The visited positions, with each line ending at a Reset
+fn lines(actions: impl Iterator<Item = Action>) -> Vec<Vec<(usize, usize)>> {
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+
+    for action in actions {
+        match action {
+            Action::Visit(x, y) => line.push((x, y)),
+            Action::Reset => lines.push(std::mem::take(&mut line)),
+        }
+    }
+
+    assert!(line.is_empty(), "Traversal doesn't end with a Reset");
+    lines
+}
+
+#[test]
+fn rows_and_columns() {
+    assert_eq!(
+        lines(traversal::rows(3, 2)),
+        [[(0, 0), (1, 0), (2, 0)], [(0, 1), (1, 1), (2, 1)]]
+    );
+    assert_eq!(
+        lines(traversal::rows_rev(3, 2)),
+        [[(2, 0), (1, 0), (0, 0)], [(2, 1), (1, 1), (0, 1)]]
+    );
+    assert_eq!(
+        lines(traversal::columns(2, 3)),
+        [[(0, 0), (0, 1), (0, 2)], [(1, 0), (1, 1), (1, 2)]]
+    );
+    assert_eq!(
+        lines(traversal::columns_rev(2, 3)),
+        [[(0, 2), (0, 1), (0, 0)], [(1, 2), (1, 1), (1, 0)]]
+    );
+}
+
+#[test]
+fn spiral() {
+    assert_eq!(
+        lines(traversal::spiral(4, 3)),
+        [
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (3, 0),
+                (3, 1),
+                (3, 2),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1)
+            ],
+            vec![(1, 1), (2, 1)],
+        ]
+    );
+}
+
+#[test]
+fn every_order_visits_every_position_once() {
+    let orders: [Order; 5] = [
+        |w, h| Box::new(traversal::rows(w, h)),
+        |w, h| Box::new(traversal::rows_rev(w, h)),
+        |w, h| Box::new(traversal::columns(w, h)),
+        |w, h| Box::new(traversal::columns_rev(w, h)),
+        |w, h| Box::new(traversal::spiral(w, h)),
+    ];
+
+    for order in orders {
+        for width in 0..6 {
+            for height in 0..6 {
+                let visited = lines(order(width, height))
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                let unique = visited.iter().copied().collect::<HashSet<_>>();
+
+                assert_eq!(visited.len(), width * height, "{width}x{height}");
+                assert_eq!(unique.len(), width * height, "{width}x{height}");
+                assert!(unique.iter().all(|&(x, y)| x < width && y < height));
+            }
+        }
+    }
+}
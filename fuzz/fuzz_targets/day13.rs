@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// whatever parses gets solved too, which should fail with an error rather than a panic
+fuzz_target!(|input: &str| {
+    if day13::parse(input).is_ok() {
+        let _ = day13::part1(input);
+        let _ = day13::part2(input);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// whatever parses gets solved too, which should fail with an error rather than a panic
+fuzz_target!(|input: &str| {
+    if day16::parse(input).is_ok() {
+        let _ = day16::part1(input);
+        let _ = day16::part2(input);
+    }
+});